@@ -0,0 +1,41 @@
+use checke_rs::bitboard::{BitBoard, MonoBitBoard};
+use checke_rs::board::{Board, BoardState};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// Scans every index of the bitboard, the way cells were iterated before bit scanning was used.
+fn scan_cells(bitboard: BitBoard) -> impl Iterator<Item = MonoBitBoard> {
+    (0..128)
+        .filter(move |index| bitboard.value() & (1 << index) != 0)
+        .map(|index| MonoBitBoard::new(1 << index).unwrap())
+}
+
+fn cell_iteration(c: &mut Criterion) {
+    let state = BoardState::default();
+    let bitboards = [
+        ("single", BitBoard::new(1 << 63)),
+        ("pieces", state.red_pieces()),
+        ("all pieces", state.red_pieces() | state.black_pieces()),
+    ];
+
+    let mut group = c.benchmark_group("cell iteration");
+    for (name, bitboard) in bitboards {
+        group.bench_with_input(BenchmarkId::new("index scan", name), &bitboard, |b, bb| {
+            b.iter(|| scan_cells(black_box(*bb)).count())
+        });
+        group.bench_with_input(BenchmarkId::new("bit scan", name), &bitboard, |b, bb| {
+            b.iter(|| black_box(*bb).used_cells().count())
+        });
+    }
+    group.finish();
+}
+
+fn legal_turns(c: &mut Criterion) {
+    let board = Board::default();
+
+    c.bench_function("legal turns", |b| {
+        b.iter(|| black_box(&board).legal_turns().count())
+    });
+}
+
+criterion_group!(benches, cell_iteration, legal_turns);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+#[path = "../tests/reference/mod.rs"]
+mod reference;
+
+use checke_rs::board::{Board, BoardState, Player};
+use checke_rs::international::InternationalRules;
+use checke_rs::position::MoveIter;
+use checke_rs::rules::{CustomRules, EnglishRules, Rules};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use reference::baseline::baseline_moves;
+use reference::{per_square_moves, played_positions};
+
+// Plays the first legal turn of every position until the game ends or the turn limit is reached.
+fn self_play<R: Rules>(rules: R, turns: usize) -> usize {
+    let mut board = Board::with_rules(rules);
+    for played in 0..turns {
+        let Some(turn) = board.legal_turns().next() else {
+            return played;
+        };
+        board.push_turn(turn).unwrap();
+    }
+    turns
+}
+
+// Generates the moves of both players in every given position, either one square at a time like
+// the generator that was replaced or with the shift-based generator behind MoveIter.
+fn bench_generators<R: Rules>(c: &mut Criterion, name: &str, positions: &[BoardState], rules: R) {
+    let mut group = c.benchmark_group("move generation");
+    group.bench_with_input(
+        BenchmarkId::new("per square", name),
+        positions,
+        |b, positions| {
+            b.iter(|| {
+                positions
+                    .iter()
+                    .flat_map(|state| [(state, Player::Red), (state, Player::Black)])
+                    .map(|(state, player)| per_square_moves(black_box(state), player, rules).len())
+                    .sum::<usize>()
+            })
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("shift", name),
+        positions,
+        |b, positions| {
+            b.iter(|| {
+                positions
+                    .iter()
+                    .flat_map(|state| [(state, Player::Red), (state, Player::Black)])
+                    .map(|(state, player)| {
+                        MoveIter::with_rules(black_box(state), player, rules).count()
+                    })
+                    .sum::<usize>()
+            })
+        },
+    );
+    group.finish();
+}
+
+// Generates the moves of the current player in every given position, either with a copy of the
+// original table and validator based iterator or with MoveIter. Both play English checkers
+// without mandatory captures, the only rules the original iterator knew.
+fn bench_baseline(c: &mut Criterion, name: &str, positions: &[BoardState]) {
+    let rules = CustomRules {
+        mandatory_capture: false,
+        ..CustomRules::default()
+    };
+    let mut group = c.benchmark_group("baseline comparison");
+    group.bench_with_input(
+        BenchmarkId::new("baseline", name),
+        positions,
+        |b, positions| {
+            b.iter(|| {
+                positions
+                    .iter()
+                    .map(|state| baseline_moves(black_box(state), state.current_player).len())
+                    .sum::<usize>()
+            })
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("shift", name),
+        positions,
+        |b, positions| {
+            b.iter(|| {
+                positions
+                    .iter()
+                    .map(|state| {
+                        MoveIter::with_rules(black_box(state), state.current_player, rules).count()
+                    })
+                    .sum::<usize>()
+            })
+        },
+    );
+    group.finish();
+}
+
+fn move_generation(c: &mut Criterion) {
+    let english = [Board::default().current_state().clone()];
+    let international = [Board::with_rules(InternationalRules)
+        .current_state()
+        .clone()];
+    bench_generators(c, "english start", &english, EnglishRules);
+    bench_generators(c, "international start", &international, InternationalRules);
+
+    let english = played_positions(EnglishRules, 4);
+    let international = played_positions(InternationalRules, 4);
+    bench_baseline(c, "english self play", &english);
+    bench_generators(c, "english self play", &english, EnglishRules);
+    bench_generators(
+        c,
+        "international self play",
+        &international,
+        InternationalRules,
+    );
+}
+
+fn self_play_loop(c: &mut Criterion) {
+    c.bench_function("self play/english", |b| {
+        b.iter(|| self_play(black_box(EnglishRules), 40))
+    });
+    c.bench_function("self play/international", |b| {
+        b.iter(|| self_play(black_box(InternationalRules), 40))
+    });
+}
+
+criterion_group!(benches, move_generation, self_play_loop);
+criterion_main!(benches);
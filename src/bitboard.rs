@@ -1,4 +1,7 @@
-use std::ops::{BitAnd, BitOr, BitXor, BitXorAssign, Range, ShlAssign, ShrAssign};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+    ShrAssign,
+};
 
 use thiserror::Error;
 
@@ -29,25 +32,84 @@ pub const BOTTOM_SQUARES: BitBoard = BitBoard(0x00000000000000FF);
 /// Represents all top and bottom squares on a checkers board.
 pub const TOP_AND_BOTTOM_SQUARES: BitBoard = BitBoard(0xFF000000000000FF);
 
-/// Bit representation of a checkers board. Backed by 64 bits; a bitboard uses bits to represent
-/// each square on the board. This bitboard implementation not only exposes various bit operations
+/// Bit representation of a checkers board. Backed by 128 bits so boards larger than the classical
+/// 8x8 board fit, which only uses the lowest 64 bits; a bitboard uses bits to represent each
+/// square on the board. This bitboard implementation not only exposes various bit operations
 /// but other domain specific helpers closely related to bitboard calculation that make board
 /// calculations easy and fast.
-#[derive(Copy, Clone, Debug)]
-pub struct BitBoard(u64);
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BitBoard(u128);
 
 impl BitBoard {
     /// Constructs a new [BitBoard] instance with the given value.
-    pub const fn new(value: u64) -> Self {
+    pub const fn new(value: u128) -> Self {
         Self(value)
     }
 
+    /// Retrieves the raw value of this bitboard.
+    pub const fn value(&self) -> u128 {
+        self.0
+    }
+
     /// Calculates whether this bitboard is empty. A bitboard is considered empty if no
     /// bits have the value of 1. In other words, the value is 0.
-    pub fn empty(&self) -> bool {
+    pub const fn empty(&self) -> bool {
         self.0 == 0
     }
 
+    /// Counts the bits that have the value of 1, which is the amount of cells on this bitboard.
+    pub const fn count_ones(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Retrieves the cell represented by the least significant bit with the value of 1, or None
+    /// if this bitboard is empty.
+    pub const fn lsb(&self) -> Option<MonoBitBoard> {
+        match self.0 {
+            0 => None,
+            value => Some(MonoBitBoard(value & value.wrapping_neg())),
+        }
+    }
+
+    /// Removes the cell represented by the least significant bit with the value of 1 from this
+    /// bitboard and returns it, or None if this bitboard is empty.
+    pub fn pop_lsb(&mut self) -> Option<MonoBitBoard> {
+        let lsb = self.lsb()?;
+        self.0 &= self.0 - 1;
+        Some(lsb)
+    }
+
+    /// Const version of the `&` operator.
+    pub const fn and(self, rhs: BitBoard) -> BitBoard {
+        BitBoard(self.0 & rhs.0)
+    }
+
+    /// Const version of the `|` operator.
+    pub const fn or(self, rhs: BitBoard) -> BitBoard {
+        BitBoard(self.0 | rhs.0)
+    }
+
+    /// Const version of the `^` operator.
+    pub const fn xor(self, rhs: BitBoard) -> BitBoard {
+        BitBoard(self.0 ^ rhs.0)
+    }
+
+    /// Const version of the `!` operator. Every bit is flipped, including the bits that do not
+    /// represent a square of the board being played on.
+    pub const fn not(self) -> BitBoard {
+        BitBoard(!self.0)
+    }
+
+    /// Const version of the `<<` operator.
+    pub const fn shl(self, rhs: u8) -> BitBoard {
+        BitBoard(self.0 << rhs)
+    }
+
+    /// Const version of the `>>` operator.
+    pub const fn shr(self, rhs: u8) -> BitBoard {
+        BitBoard(self.0 >> rhs)
+    }
+
     /// Returns a [CellIter] that will be capable of iterating over every active bit on this board.
     pub fn used_cells(&self) -> CellIter {
         CellIter::new(*self)
@@ -60,6 +122,12 @@ impl BitBoard {
     }
 }
 
+impl From<MonoBitBoard> for BitBoard {
+    fn from(value: MonoBitBoard) -> Self {
+        BitBoard(value.0)
+    }
+}
+
 impl BitAnd for BitBoard {
     type Output = BitBoard;
 
@@ -100,6 +168,66 @@ impl BitXor for BitBoard {
     }
 }
 
+impl Not for BitBoard {
+    type Output = BitBoard;
+
+    fn not(self) -> Self::Output {
+        BitBoard(!self.0)
+    }
+}
+
+impl Shl<u8> for BitBoard {
+    type Output = BitBoard;
+
+    fn shl(self, rhs: u8) -> Self::Output {
+        BitBoard(self.0 << rhs)
+    }
+}
+
+impl Shr<u8> for BitBoard {
+    type Output = BitBoard;
+
+    fn shr(self, rhs: u8) -> Self::Output {
+        BitBoard(self.0 >> rhs)
+    }
+}
+
+impl BitAndAssign for BitBoard {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0
+    }
+}
+
+impl BitAndAssign<MonoBitBoard> for BitBoard {
+    fn bitand_assign(&mut self, rhs: MonoBitBoard) {
+        self.0 &= rhs.0
+    }
+}
+
+impl BitOrAssign for BitBoard {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0
+    }
+}
+
+impl BitOrAssign<MonoBitBoard> for BitBoard {
+    fn bitor_assign(&mut self, rhs: MonoBitBoard) {
+        self.0 |= rhs.0
+    }
+}
+
+impl ShlAssign<u8> for BitBoard {
+    fn shl_assign(&mut self, rhs: u8) {
+        self.0 <<= rhs
+    }
+}
+
+impl ShrAssign<u8> for BitBoard {
+    fn shr_assign(&mut self, rhs: u8) {
+        self.0 >>= rhs
+    }
+}
+
 impl BitXorAssign for BitBoard {
     fn bitxor_assign(&mut self, rhs: Self) {
         self.0 ^= rhs.0
@@ -118,12 +246,17 @@ pub struct MonoBitBoardError;
 
 /// Special type of bitboard that enforces that only a single bit has the value of 1.
 /// This can be useful when representing a piece or single cell using bitboard and type safety.
-#[derive(Copy, Clone, Debug, PartialOrd)]
-pub struct MonoBitBoard(u64);
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd)]
+pub struct MonoBitBoard(u128);
 
 impl MonoBitBoard {
+    /// Retrieves the raw value of this bitboard.
+    pub const fn value(&self) -> u128 {
+        self.0
+    }
+
     /// Attempt to create a [MonoBitBoard] with the given value.
-    pub fn new(value: u64) -> Result<Self, MonoBitBoardError> {
+    pub fn new(value: u128) -> Result<Self, MonoBitBoardError> {
         let is_single_piece = value != 0 && (value & (value - 1)) == 0;
         match is_single_piece {
             true => Ok(MonoBitBoard(value)),
@@ -171,14 +304,8 @@ impl TryFrom<BitBoard> for MonoBitBoard {
 
 macro_rules! impl_equals {
     ($x:ident, $y:ident) => {
-        impl PartialEq for $x {
-            fn eq(&self, other: &Self) -> bool {
-                self.0 == other.0
-            }
-        }
-
-        impl PartialEq<u64> for $x {
-            fn eq(&self, other: &u64) -> bool {
+        impl PartialEq<u128> for $x {
+            fn eq(&self, other: &u128) -> bool {
                 self.0 == *other
             }
         }
@@ -195,18 +322,18 @@ impl_equals!(MonoBitBoard, BitBoard);
 impl_equals!(BitBoard, MonoBitBoard);
 
 /// Iterator capable of producing a [MonoBitBoard] for each active cell of a given [BitBoard].
+/// Cells are produced from the least to the most significant bit by isolating and clearing the
+/// lowest set bit, see [BitBoard::pop_lsb], so that empty bits are never visited. Iterating from
+/// the back isolates the highest set bit by counting leading zeros instead.
+#[derive(Clone, Debug)]
 pub struct CellIter {
     bitboard: BitBoard,
-    iter: Range<usize>,
 }
 
 impl CellIter {
     /// Creates a new iterator instance with the given [BitBoard]
     pub fn new(bitboard: BitBoard) -> Self {
-        CellIter {
-            bitboard,
-            iter: 0..64,
-        }
+        CellIter { bitboard }
     }
 }
 
@@ -214,9 +341,26 @@ impl Iterator for CellIter {
     type Item = MonoBitBoard;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .by_ref()
-            .find(|index| self.bitboard.0 & (1 << index) != 0)
-            .map(|index| MonoBitBoard::new(1 << index).unwrap())
+        self.bitboard.pop_lsb()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bitboard.count_ones() as usize;
+        (len, Some(len))
     }
 }
+
+impl DoubleEndedIterator for CellIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let value = self.bitboard.0;
+        if value == 0 {
+            return None;
+        }
+
+        let msb = 1 << (u128::BITS - 1 - value.leading_zeros());
+        self.bitboard.0 ^= msb;
+        Some(MonoBitBoard(msb))
+    }
+}
+
+impl ExactSizeIterator for CellIter {}
@@ -1,10 +1,13 @@
+use std::cell::OnceCell;
 use std::collections::VecDeque;
 
 use thiserror::Error;
 
 use crate::bitboard::{BitBoard, MonoBitBoard};
-use crate::position::{Move, MoveError, MoveIter, MoveValidator, Square};
-use crate::turn::Turn;
+use crate::position::{Move, MoveError};
+use crate::rules::{EnglishRules, Rules, WinCondition};
+use crate::topology::Topology;
+use crate::turn::{LegalTurnIter, PlayedTurn, Turn, TurnValidator};
 
 pub const INITIAL_RED_PIECES: BitBoard =
     BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10101010_01010101_10101010);
@@ -15,6 +18,10 @@ pub const INITIAL_KINGS: BitBoard = BitBoard::new(0);
 pub const KING_SQUARES: BitBoard =
     BitBoard::new(0b01010101_00000000_00000000_00000000_00000000_00000000_00000000_10101010);
 
+/// Default amount of turns, counted for both players, that may be played without a capture or a
+/// man being moved before the game is considered drawn. Equal to 40 moves per player.
+pub const DEFAULT_DRAW_TURN_LIMIT: usize = 80;
+
 /// Represents the current status of a board instance.
 #[derive(Debug, PartialEq)]
 pub enum BoardStatus {
@@ -25,6 +32,19 @@ pub enum BoardStatus {
     /// The player to move no longer have any valid moves and therefore the game has been
     /// completed.
     Complete { winner: Player },
+
+    /// The game has ended without a winner.
+    Draw { reason: DrawReason },
+}
+
+/// Represents the reason a game has been drawn.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DrawReason {
+    /// The same board state, including the player to move, occurred for the third time.
+    ThreefoldRepetition,
+
+    /// The configured amount of turns has been played without a capture or a man being moved.
+    TurnLimit,
 }
 
 /// Represents the player disc color
@@ -34,7 +54,11 @@ pub enum Player {
     Black,
 }
 
-/// Represents the state a classical checkers board may be in.
+/// Represents the state a board of any [Topology] may be in: whose turn it is and which cells
+/// hold red and black pieces and kings. The state carries no topology itself, its bitboards being
+/// laid out as described by the topology of the rules it is played with. Default states hold the
+/// starting position of English checkers on the classical
+/// [EightByEight](crate::topology::EightByEight) board.
 #[derive(Clone, Debug, PartialEq)]
 pub struct BoardState {
     pub current_player: Player,
@@ -110,7 +134,7 @@ impl BoardState {
     /// Returns true if there is a piece occupying the given square and it belongs to the player
     /// not currently active. Otherwise, returns false.
     pub fn is_other_player_piece(&self, bitboard: MonoBitBoard) -> bool {
-        !self.is_current_player_piece(bitboard)
+        self.pieces_by_player(self.next_player()) & bitboard != 0
     }
 
     /// Returns true if there is a piece occupying the given square. Otherwise, returns false.
@@ -140,7 +164,7 @@ impl BoardState {
 
     /// Retrieves a bitboard representing where the black king pieces are on the board.
     pub fn black_kings(&self) -> BitBoard {
-        self.black_pieces() & self.all_pieces()
+        self.black_pieces() & self.all_kings()
     }
 
     /// Retrieves a bitboard representing where all kings of a specified player are on the board.
@@ -151,6 +175,12 @@ impl BoardState {
         }
     }
 
+    /// Retrieves a bitboard representing where all men (pieces that are not kings) of a specified
+    /// player are on the board.
+    pub fn men_by_player(&self, player: Player) -> BitBoard {
+        self.pieces_by_player(player) ^ self.kings_by_player(player)
+    }
+
     /// Retrieves a bitboard representing all king pieces are on the board.
     pub fn all_kings(&self) -> BitBoard {
         self.kings
@@ -164,33 +194,103 @@ impl BoardState {
     pub fn is_king_row(&self, bitboard: MonoBitBoard) -> bool {
         KING_SQUARES & bitboard != 0
     }
+
+    /// Returns a [LegalTurnIter] capable of iterating over every complete turn the current player
+    /// is able to legally take according to [EnglishRules] on the classical board.
+    pub fn legal_turns(&self) -> LegalTurnIter {
+        LegalTurnIter::new(self)
+    }
+
+    // Method used to move the current player's piece with move details. Captured pieces are left
+    // on the board and crowning is left to the caller.
+    pub(crate) fn move_piece(&mut self, m: &Move) {
+        let move_mask = m.mask();
+
+        if self.is_king(m.source()) {
+            self.kings ^= move_mask;
+        }
+
+        match self.current_player {
+            Player::Red => self.red_pieces ^= move_mask,
+            Player::Black => self.black_pieces ^= move_mask,
+        }
+    }
+
+    // Method used to crown the piece on the given cell.
+    pub(crate) fn crown(&mut self, cell: MonoBitBoard) {
+        self.kings |= cell;
+    }
+
+    // Method used to remove every given piece from the board, regardless of the owning player.
+    pub(crate) fn remove_pieces(&mut self, pieces: BitBoard) {
+        self.red_pieces ^= self.red_pieces & pieces;
+        self.black_pieces ^= self.black_pieces & pieces;
+        self.kings ^= self.kings & pieces;
+    }
 }
 
-/// A board value is used to track and moderate the progress of a checkers game.
+/// A board value is used to track and moderate the progress of a checkers game. The game is
+/// played according to a set of [Rules], which default to [EnglishRules].
 #[derive(Debug)]
-pub struct Board {
+pub struct Board<R: Rules = EnglishRules> {
     history: VecDeque<BoardState>,
+    turns: Vec<Turn<R::Topology>>,
+    rules: R,
+    draw_turn_limit: Option<usize>,
+    // Legal turns of the current state paired with the state they leave the board in, generated
+    // once per position and shared by the status, the validation and the listing of turns.
+    legal_turns: OnceCell<Vec<PlayedTurn<R::Topology>>>,
 }
 
 impl Default for Board {
     /// Creates a fresh board with pieces in starting positions.
     fn default() -> Self {
-        let initial_state = BoardState::default();
-        Board::new(initial_state)
+        Board::with_rules(EnglishRules)
     }
 }
 
 impl Board {
-    pub(crate) fn new(initial_state: BoardState) -> Self {
+    /// Creates an empty [Board] instance.
+    pub fn empty() -> Self {
+        Board::empty_with_rules(EnglishRules)
+    }
+}
+
+impl<R: Rules> Board<R> {
+    pub(crate) fn new(initial_state: BoardState, rules: R) -> Self {
         Board {
             history: VecDeque::from([initial_state]),
+            turns: vec![],
+            rules,
+            draw_turn_limit: Some(DEFAULT_DRAW_TURN_LIMIT),
+            legal_turns: OnceCell::new(),
         }
     }
 
-    /// Creates an empty [Board] instance.
-    pub fn empty() -> Self {
-        let initial_state = BoardState::empty();
-        Board::new(initial_state)
+    /// Creates a fresh board with pieces in starting positions that is played according to the
+    /// given rules.
+    pub fn with_rules(rules: R) -> Self {
+        let initial_state = BoardState {
+            current_player: rules.first_player(),
+            red_pieces: R::Topology::initial_pieces(Player::Red),
+            black_pieces: R::Topology::initial_pieces(Player::Black),
+            kings: INITIAL_KINGS,
+        };
+        Board::new(initial_state, rules)
+    }
+
+    /// Creates an empty [Board] instance that is played according to the given rules.
+    pub fn empty_with_rules(rules: R) -> Self {
+        let initial_state = BoardState {
+            current_player: rules.first_player(),
+            ..BoardState::empty()
+        };
+        Board::new(initial_state, rules)
+    }
+
+    /// Returns the rules this board is played with.
+    pub fn rules(&self) -> &R {
+        &self.rules
     }
 
     /// Returns the board's initial state
@@ -212,23 +312,65 @@ impl Board {
     }
 
     /// Calculates the current status of the game based on if the boards current player
-    /// has any available moves to make.
+    /// has any legal turns to take, including the limit the rules put on consecutive king moves,
+    /// deciding the winner by the [WinCondition] of the rules, and whether the game has been drawn
+    /// by repetition or by exceeding the draw turn limit.
     pub fn status(&self) -> BoardStatus {
         let current_state = self.current_state();
-        let mut player_moves = MoveIter::new(current_state, current_state.current_player);
-        match player_moves.next() {
-            Some(_) => BoardStatus::OnGoing,
-            None => BoardStatus::Complete {
-                winner: current_state.next_player(),
-            },
+        if self.played_turns().is_empty() {
+            let winner = match self.rules.win_condition() {
+                WinCondition::Standard => current_state.next_player(),
+                WinCondition::Inverted => current_state.current_player(),
+            };
+            return BoardStatus::Complete { winner };
+        }
+
+        match self.draw_reason() {
+            Some(reason) => BoardStatus::Draw { reason },
+            None => BoardStatus::OnGoing,
+        }
+    }
+
+    fn draw_reason(&self) -> Option<DrawReason> {
+        let current_state = self.current_state();
+        let repetitions = self
+            .history
+            .iter()
+            .filter(|state| *state == current_state)
+            .count();
+        if repetitions >= 3 {
+            return Some(DrawReason::ThreefoldRepetition);
+        }
+
+        match self.draw_turn_limit {
+            Some(limit) if self.quiet_turn_count() >= limit => Some(DrawReason::TurnLimit),
+            _ => None,
         }
     }
 
+    /// Counts the amount of consecutive turns, ending with the most recent one, in which no piece
+    /// was captured and no man was moved.
+    pub fn quiet_turn_count(&self) -> usize {
+        let states = self.history.iter().rev();
+        states
+            .clone()
+            .zip(states.skip(1))
+            .take_while(|(next, previous)| {
+                let mover = previous.current_player();
+                let opponent = previous.next_player();
+                let is_capture =
+                    previous.pieces_by_player(opponent) != next.pieces_by_player(opponent);
+                let is_man_move = previous.men_by_player(mover) != next.men_by_player(mover);
+                !is_capture && !is_man_move
+            })
+            .count()
+    }
+
     /// Similar to [Board::status] but provides a simple yes or no answer to if the game
     /// is still in progress.
     pub fn is_game_concluded(&self) -> bool {
         match self.status() {
-            BoardStatus::Complete { .. } => true,
+            BoardStatus::Complete { .. } | BoardStatus::Draw { .. } => true,
             BoardStatus::OnGoing => false,
         }
     }
@@ -237,7 +379,7 @@ impl Board {
     /// turn is provided.
     pub fn push_turn<T>(&mut self, turn: T) -> Result<&BoardState, MoveError>
     where
-        T: TryInto<Turn>,
+        T: TryInto<Turn<R::Topology>>,
     {
         if self.is_game_concluded() {
             return Err(MoveError::GameConcluded);
@@ -246,55 +388,142 @@ impl Board {
         let turn = turn
             .try_into()
             .map_err(|_| MoveError::InvalidConstruction)?;
-        let mut board_state = self.current_state().clone();
-        for m in turn.moves() {
-            let validator = MoveValidator::new(&board_state);
-            validator.validate(m.clone())?;
-
-            Board::apply_move(&mut board_state, m)
-        }
+        let legal_turn = self
+            .played_turns()
+            .iter()
+            .find(|(legal_turn, _)| legal_turn.is_played_as(&turn))
+            .cloned();
+        let (mut board_state, turn) = match legal_turn {
+            Some((turn, board_state)) => (board_state, turn),
+            None => self.play(&turn)?,
+        };
 
         board_state.current_player = board_state.next_player();
         self.history.push_back(board_state);
+        self.turns.push(turn);
+        self.legal_turns.take();
         Ok(self.current_state())
     }
 
-    // Method used to mutate a board state value with move details
-    fn apply_move(board_state: &mut BoardState, m: &Move) {
-        let move_mask = m.mask();
+    // Validates a turn that is not one of the legal turns of the current state in order to
+    // describe why it can not be played.
+    fn play(&self, turn: &Turn<R::Topology>) -> Result<(BoardState, Turn<R::Topology>), MoveError> {
+        let validator = TurnValidator::with_rules(self.current_state(), self.rules)
+            .with_legal_turns(self.played_turns());
+        let (board_state, turn) = validator.play(turn)?;
+        if let Some((king, _)) = quiet_king_move(self.current_state(), &board_state) {
+            self.valid_king_move_limit(king)?;
+        }
+        Ok((board_state, turn))
+    }
 
-        if board_state.is_king(m.source()) {
-            board_state.kings ^= move_mask;
+    /// Removes the last turn and returns the state of the board, or None if only the
+    /// initial state remains on the stack.
+    pub fn pop_turn(&mut self) -> Option<BoardState> {
+        match self.history.len() {
+            // There should always be at least one state item on the stack.
+            1 => None,
+            _ => {
+                self.turns.pop();
+                self.legal_turns.take();
+                self.history.pop_back()
+            }
         }
+    }
 
-        match board_state.current_player {
-            Player::Red => {
-                board_state.red_pieces ^= move_mask;
-                if let Some(capture) = m.capture() {
-                    board_state.black_pieces ^= capture;
-                }
+    /// Returns the amount of turns that may be played without a capture or a man being moved
+    /// before the game is drawn, or None if the game can not be drawn this way.
+    pub fn draw_turn_limit(&self) -> Option<usize> {
+        self.draw_turn_limit
+    }
+
+    /// Sets the amount of turns, counted for both players, that may be played without a capture
+    /// or a man being moved before the game is drawn. Boards start out with
+    /// [DEFAULT_DRAW_TURN_LIMIT], and None disables the rule.
+    pub fn set_draw_turn_limit(&mut self, limit: Option<usize>) -> &mut Self {
+        self.draw_turn_limit = limit;
+        self
+    }
+
+    /// Returns a [LegalTurnIter] capable of iterating over every complete turn the current player
+    /// is able to legally take on this board.
+    pub fn legal_turns(&self) -> LegalTurnIter<R::Topology> {
+        LegalTurnIter::from_played(self.played_turns().to_vec())
+    }
+
+    // Retrieves every legal turn of the current state paired with the state it leaves the board
+    // in. Turns are generated once per position and reused until the position changes.
+    fn played_turns(&self) -> &[PlayedTurn<R::Topology>] {
+        self.legal_turns.get_or_init(|| {
+            let current_state = self.current_state();
+            let turns = LegalTurnIter::with_rules(current_state, self.rules);
+            if self.rules.king_move_limit().is_none() {
+                return turns.into_played();
             }
-            Player::Black => {
-                board_state.black_pieces ^= move_mask;
-                if let Some(capture) = m.capture() {
-                    board_state.red_pieces ^= capture;
+
+            turns
+                .retain(|turn| match turn.moves().as_slice() {
+                    [m] if m.capture().is_none() && current_state.is_king(m.source()) => {
+                        self.valid_king_move_limit(m.source()).is_ok()
+                    }
+                    _ => true,
+                })
+                .into_played()
+        })
+    }
+
+    /// Counts the amount of consecutive non-capturing moves, ending with the current player's most
+    /// recent turn, that were made by the king on the given cell.
+    pub fn consecutive_king_moves(&self, king: MonoBitBoard) -> usize {
+        // Every other state, starting from the previous one, follows a turn of the current player.
+        let states = self
+            .history
+            .iter()
+            .rev()
+            .skip(1)
+            .collect::<Vec<&BoardState>>();
+        let mut king = king;
+        let mut moves = 0;
+        for pair in states.chunks_exact(2) {
+            match quiet_king_move(pair[1], pair[0]) {
+                Some((source, destination)) if destination == king => {
+                    king = source;
+                    moves += 1;
                 }
+                _ => break,
             }
         }
+        moves
+    }
 
-        if board_state.is_king_row(m.destination()) {
-            board_state.kings ^= m.destination()
+    // Validates the king on the given cell may make another non-capturing move.
+    fn valid_king_move_limit(&self, king: MonoBitBoard) -> Result<(), MoveError> {
+        let current_state = self.current_state();
+        let Some(limit) = self.rules.king_move_limit() else {
+            return Ok(());
+        };
+        if current_state
+            .men_by_player(current_state.current_player)
+            .empty()
+        {
+            return Ok(());
         }
-    }
 
-    /// Removes the last turn and returns the state of the board, or None if only the
-    /// initial state remains on the stack.
-    pub fn pop_turn(&mut self) -> Option<BoardState> {
-        match self.history.len() {
-            // There should always be at least one state item on the stack.
-            1 => None,
-            _ => self.history.pop_back(),
+        if self.consecutive_king_moves(king) >= limit {
+            let piece = R::Topology::number(king).ok_or(MoveError::InvalidConstruction)?;
+            return Err(MoveError::KingMoveLimitReached {
+                piece,
+                moves: limit,
+            });
         }
+        Ok(())
+    }
+
+    /// Returns every turn played on the board in the order they were played in. Each turn is
+    /// recorded as it was resolved against the board, so captures made by flying kings include
+    /// the captured piece.
+    pub fn turns(&self) -> &[Turn<R::Topology>] {
+        &self.turns
     }
 
     /// Returns a reference to the boards state stack. Useful for viewing the history of
@@ -304,6 +533,28 @@ impl Board {
     }
 }
 
+// Retrieves the source and destination of the king moved between the given states if the turn
+// played was a non-capturing king move.
+fn quiet_king_move(
+    previous: &BoardState,
+    next: &BoardState,
+) -> Option<(MonoBitBoard, MonoBitBoard)> {
+    let mover = previous.current_player();
+    let opponent = previous.next_player();
+    let is_capture = previous.pieces_by_player(opponent) != next.pieces_by_player(opponent);
+    let is_man_move = previous.men_by_player(mover) != next.men_by_player(mover);
+    if is_capture || is_man_move {
+        return None;
+    }
+
+    let previous_kings = previous.kings_by_player(mover);
+    let next_kings = next.kings_by_player(mover);
+    let moved = previous_kings ^ next_kings;
+    let source = MonoBitBoard::try_from(moved & previous_kings).ok()?;
+    let destination = MonoBitBoard::try_from(moved & next_kings).ok()?;
+    Some((source, destination))
+}
+
 #[derive(Debug, Error, PartialEq)]
 pub enum BoardCreationError {
     #[error("Only a single piece can be placed per square.")]
@@ -313,41 +564,64 @@ pub enum BoardCreationError {
 #[derive(Debug)]
 struct Placement {
     player: Player,
-    square: Square,
+    cell: MonoBitBoard,
     is_king: bool,
 }
 
+// Square type of the board played according to the given rules.
+type Square<R> = <<R as Rules>::Topology as Topology>::Square;
+
 /// Used to create complex but safe board configurations with a builder pattern.
 #[derive(Debug)]
-pub struct BoardBuilder {
+pub struct BoardBuilder<R = EnglishRules> {
     current_player: Player,
+    rules: R,
+    draw_turn_limit: Option<usize>,
     placements: Vec<Placement>,
 }
 
-impl BoardBuilder {
+impl<R: Rules> BoardBuilder<R> {
+    /// Creates a new [BoardBuilder] instance for a board played according to the given rules.
+    /// The current player defaults to the first player of the rules.
+    pub fn with_rules(rules: R) -> Self {
+        BoardBuilder {
+            current_player: rules.first_player(),
+            rules,
+            draw_turn_limit: Some(DEFAULT_DRAW_TURN_LIMIT),
+            placements: vec![],
+        }
+    }
+
     /// Sets the current player the board will be built with.
     pub fn current_player(&mut self, player: Player) -> &mut Self {
         self.current_player = player;
         self
     }
 
-    /// Adds a normal piece on the board.
-    pub fn piece(&mut self, player: Player, square: Square) -> &mut Self {
-        let placement = Placement {
-            player,
-            square,
-            is_king: false,
-        };
-        self.placements.push(placement);
+    /// Sets the amount of turns, counted for both players, that may be played without a capture or
+    /// a man being moved before the game is drawn. None disables the rule.
+    pub fn draw_turn_limit(&mut self, limit: Option<usize>) -> &mut Self {
+        self.draw_turn_limit = limit;
         self
     }
 
+    /// Adds a normal piece on the board.
+    pub fn piece(&mut self, player: Player, square: Square<R>) -> &mut Self {
+        self.place(player, square, false)
+    }
+
     /// Adds a king piece on the board.
-    pub fn king(&mut self, player: Player, square: Square) -> &mut Self {
+    pub fn king(&mut self, player: Player, square: Square<R>) -> &mut Self {
+        self.place(player, square, true)
+    }
+
+    fn place(&mut self, player: Player, square: Square<R>, is_king: bool) -> &mut Self {
+        // Every square of a topology names one of its cells.
+        let cell = R::Topology::cell(square.into()).unwrap();
         let placement = Placement {
             player,
-            square,
-            is_king: true,
+            cell,
+            is_king,
         };
         self.placements.push(placement);
         self
@@ -356,24 +630,24 @@ impl BoardBuilder {
     /// Attempts to construct a new [Board] instance given the previous details.
     /// An error will be returned if during the build process multiple pieces were placed
     /// on the same square.
-    pub fn build(&self) -> Result<Board, BoardCreationError> {
+    pub fn build(&self) -> Result<Board<R>, BoardCreationError> {
         let mut red_pieces = BitBoard::new(0);
         let mut black_pieces = BitBoard::new(0);
         let mut kings = BitBoard::new(0);
 
         for placement in &self.placements {
-            let piece = MonoBitBoard::from(placement.square);
+            let piece = placement.cell;
             if (red_pieces | black_pieces).contains(piece) {
                 return Err(BoardCreationError::DuplicateAssignments);
             }
 
             match placement.player {
-                Player::Red => red_pieces = red_pieces | piece,
-                Player::Black => black_pieces = black_pieces | piece,
+                Player::Red => red_pieces |= piece,
+                Player::Black => black_pieces |= piece,
             }
 
             if placement.is_king {
-                kings = kings | piece
+                kings |= piece
             }
         }
 
@@ -384,16 +658,14 @@ impl BoardBuilder {
             black_pieces,
             kings,
         };
-        let board = Board::new(initial_state);
+        let mut board = Board::new(initial_state, self.rules);
+        board.set_draw_turn_limit(self.draw_turn_limit);
         Ok(board)
     }
 }
 
 impl Default for BoardBuilder {
     fn default() -> Self {
-        BoardBuilder {
-            current_player: Player::Black,
-            placements: vec![],
-        }
+        BoardBuilder::with_rules(EnglishRules)
     }
 }
@@ -0,0 +1,195 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::board::{Board, BoardState, Player};
+use crate::rules::{EnglishRules, Rules};
+use crate::topology::{EightByEight, Topology};
+
+/// Error that can occur while reading a position written in the FEN notation of PDN.
+#[derive(Debug, Error, PartialEq)]
+pub enum FenError {
+    #[error("FEN must consist of the player to move followed by the pieces of both players, all separated by colons.")]
+    InvalidFormat,
+
+    #[error("'{0}' is not a player. Players are written as W for white, the role of red, or B for black.")]
+    InvalidPlayer(String),
+
+    #[error("'{0}' is not a square of the board being played on.")]
+    InvalidSquare(String),
+
+    #[error("The pieces of {0:?} are listed more than once.")]
+    DuplicatePlayer(Player),
+
+    #[error("Square {0} is occupied by more than one piece.")]
+    DuplicateSquare(u8),
+}
+
+impl BoardState {
+    /// Attempts to create a [BoardState] from a FEN string of a classical checkers board, such as
+    /// `B:W18,24,27,K28:B12,16,K32`. The string names the player to move followed by the pieces
+    /// of each player, kings being prefixed with a K.
+    pub fn from_fen(text: &str) -> Result<Self, FenError> {
+        BoardState::parse_fen::<EightByEight>(text)
+    }
+
+    /// Attempts to create a [BoardState] from a FEN string, numbering squares according to the
+    /// given topology. Besides single squares, pieces may be listed as ranges of squares such as
+    /// `W31-50`, and the string may end with a period.
+    pub fn parse_fen<T: Topology>(text: &str) -> Result<Self, FenError> {
+        let text = text.trim();
+        let text = text.strip_suffix('.').unwrap_or(text);
+        let mut sections = text.split(':');
+        let player_text = sections.next().unwrap_or_default().trim();
+        let current_player = parse_player(player_text)
+            .ok_or_else(|| FenError::InvalidPlayer(player_text.to_string()))?;
+
+        let mut board_state = BoardState {
+            current_player,
+            ..BoardState::empty()
+        };
+        let mut players = vec![];
+        for section in sections {
+            let section = section.trim();
+            let player_text = section.get(..1).unwrap_or_default();
+            let player = parse_player(player_text)
+                .ok_or_else(|| FenError::InvalidPlayer(player_text.to_string()))?;
+            if players.contains(&player) {
+                return Err(FenError::DuplicatePlayer(player));
+            }
+            players.push(player);
+
+            let pieces = section[1..].trim();
+            if pieces.is_empty() {
+                continue;
+            }
+            for piece in pieces.split(',').map(str::trim) {
+                board_state.place_fen_pieces::<T>(player, piece)?;
+            }
+        }
+
+        match players.len() {
+            2 => Ok(board_state),
+            _ => Err(FenError::InvalidFormat),
+        }
+    }
+
+    /// Writes the state as a FEN string of a classical checkers board. Squares are listed in
+    /// ascending order, so that reading the string back results in the same state.
+    pub fn to_fen(&self) -> String {
+        self.format_fen::<EightByEight>()
+    }
+
+    /// Writes the state as a FEN string, numbering squares according to the given topology.
+    pub fn format_fen<T: Topology>(&self) -> String {
+        format!(
+            "{}:W{}:B{}",
+            player_letter(self.current_player),
+            self.fen_pieces::<T>(Player::Red),
+            self.fen_pieces::<T>(Player::Black)
+        )
+    }
+
+    // Places the pieces written as a single square or as a range of squares, either of which may
+    // be prefixed with a K to place kings.
+    fn place_fen_pieces<T: Topology>(
+        &mut self,
+        player: Player,
+        text: &str,
+    ) -> Result<(), FenError> {
+        let invalid_square = || FenError::InvalidSquare(text.to_string());
+        let (king, squares) = match text.strip_prefix('K') {
+            Some(squares) => (true, squares),
+            None => (false, text),
+        };
+        let (first, last) = squares.split_once('-').unwrap_or((squares, squares));
+        let first = first.parse::<u8>().map_err(|_| invalid_square())?;
+        let last = last.parse::<u8>().map_err(|_| invalid_square())?;
+        if first > last {
+            return Err(invalid_square());
+        }
+
+        for number in first..=last {
+            let cell = T::cell(number).ok_or_else(invalid_square)?;
+            if self.is_piece(cell) {
+                return Err(FenError::DuplicateSquare(number));
+            }
+            match player {
+                Player::Red => self.red_pieces |= cell,
+                Player::Black => self.black_pieces |= cell,
+            }
+            if king {
+                self.kings |= cell;
+            }
+        }
+        Ok(())
+    }
+
+    // Lists the squares of the given player's pieces in ascending order.
+    fn fen_pieces<T: Topology>(&self, player: Player) -> String {
+        let mut pieces = self
+            .pieces_by_player(player)
+            .used_cells()
+            .filter_map(|cell| T::number(cell).map(|number| (number, self.is_king(cell))))
+            .collect::<Vec<(u8, bool)>>();
+        pieces.sort();
+
+        pieces
+            .iter()
+            .map(|(number, king)| match king {
+                true => format!("K{number}"),
+                false => number.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+impl Board {
+    /// Attempts to create a [Board] played according to [EnglishRules] that starts from the
+    /// position of a FEN string of a classical checkers board. See [BoardState::from_fen].
+    pub fn from_fen(text: &str) -> Result<Self, FenError> {
+        Board::from_fen_with_rules(text, EnglishRules)
+    }
+}
+
+impl<R: Rules> Board<R> {
+    /// Attempts to create a [Board] played according to the given rules that starts from the
+    /// position of a FEN string, numbering squares according to the topology of the rules.
+    pub fn from_fen_with_rules(text: &str, rules: R) -> Result<Self, FenError> {
+        let initial_state = BoardState::parse_fen::<R::Topology>(text)?;
+        Ok(Board::new(initial_state, rules))
+    }
+}
+
+/// Allows FEN strings of classical checkers boards to be easily converted into board states.
+impl FromStr for BoardState {
+    type Err = FenError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        BoardState::from_fen(s)
+    }
+}
+
+/// Writes the state as a FEN string of a classical checkers board.
+impl Display for BoardState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_fen())
+    }
+}
+
+fn parse_player(text: &str) -> Option<Player> {
+    match text {
+        "W" | "w" => Some(Player::Red),
+        "B" | "b" => Some(Player::Black),
+        _ => None,
+    }
+}
+
+fn player_letter(player: Player) -> char {
+    match player {
+        Player::Red => 'W',
+        Player::Black => 'B',
+    }
+}
@@ -0,0 +1,104 @@
+use crate::bitboard::{BitBoard, MonoBitBoard};
+use crate::board::Player;
+use crate::international::{Square50, TenByTen};
+use crate::rules::{CapturePriority, PromotionRule, Rules};
+use crate::topology::{Direction, Topology};
+
+/// The 10x10 board of Frisian draughts. Squares are numbered and packed exactly like on the
+/// [TenByTen] board, but pieces also capture along rows and columns, jumping over the nearest
+/// playable square in that direction, which sits two cells away.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrisianTenByTen;
+
+impl Topology for FrisianTenByTen {
+    type Square = Square50;
+
+    const SIZE: u8 = TenByTen::SIZE;
+
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        TenByTen::step(cell, direction)
+    }
+
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        TenByTen::shift(cells, direction)
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        TenByTen::cell(number)
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        TenByTen::number(cell)
+    }
+
+    fn coordinates(cell: MonoBitBoard) -> Option<(u8, u8)> {
+        TenByTen::coordinates(cell)
+    }
+
+    fn cell_at(row: u8, column: u8) -> Option<MonoBitBoard> {
+        TenByTen::cell_at(row, column)
+    }
+
+    fn initial_pieces(player: Player) -> BitBoard {
+        TenByTen::initial_pieces(player)
+    }
+
+    fn promotion_squares(player: Player) -> BitBoard {
+        TenByTen::promotion_squares(player)
+    }
+
+    fn capture_directions() -> &'static [Direction] {
+        &Direction::ALL
+    }
+}
+
+/// Rules of Frisian draughts, played on the [FrisianTenByTen] board. Red takes the role of white
+/// and moves first. Pieces move diagonally but capture diagonally and orthogonally, men capture
+/// backwards and kings fly. The capture of the highest value must be taken, a king being worth
+/// more than one man but less than two, and a king must capture when it ties with a man. A king
+/// may make no more than three non-capturing moves in a row while its player still has men.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrisianRules;
+
+impl Rules for FrisianRules {
+    type Topology = FrisianTenByTen;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        true
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        true
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::ContinuesAsMan
+    }
+
+    fn king_move_limit(&self) -> Option<usize> {
+        Some(3)
+    }
+
+    fn capture_priorities(&self) -> &'static [CapturePriority] {
+        &[
+            CapturePriority::HighestValue { man: 2, king: 3 },
+            CapturePriority::CapturingKing,
+        ]
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(40)
+    }
+}
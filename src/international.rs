@@ -0,0 +1,151 @@
+use crate::bitboard::{BitBoard, MonoBitBoard};
+use crate::board::Player;
+use crate::position::square_type;
+use crate::rules::{PromotionRule, Rules};
+use crate::topology::{Direction, PackedGrid, Topology};
+
+square_type! {
+    /// Represents every valid square on an international draughts board. Square 1 sits on black's
+    /// side of the board while red, playing the role of white, starts on squares 31 through 50.
+    Square50, TenByTen => [
+        One,
+        Two,
+        Three,
+        Four,
+        Five,
+        Six,
+        Seven,
+        Eight,
+        Nine,
+        Ten,
+        Eleven,
+        Twelve,
+        Thirteen,
+        Fourteen,
+        Fifteen,
+        Sixteen,
+        Seventeen,
+        Eighteen,
+        Nineteen,
+        Twenty,
+        TwentyOne,
+        TwentyTwo,
+        TwentyThree,
+        TwentyFour,
+        TwentyFive,
+        TwentySix,
+        TwentySeven,
+        TwentyEight,
+        TwentyNine,
+        Thirty,
+        ThirtyOne,
+        ThirtyTwo,
+        ThirtyThree,
+        ThirtyFour,
+        ThirtyFive,
+        ThirtySix,
+        ThirtySeven,
+        ThirtyEight,
+        ThirtyNine,
+        Forty,
+        FortyOne,
+        FortyTwo,
+        FortyThree,
+        FortyFour,
+        FortyFive,
+        FortySix,
+        FortySeven,
+        FortyEight,
+        FortyNine,
+        Fifty,
+    ]
+}
+
+/// The 10x10 international draughts board with 50 playable squares. Squares are packed into the
+/// lowest 50 bits of a bitboard, square `n` being represented by bit `n - 1`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TenByTen;
+
+type Grid = PackedGrid<10>;
+
+impl Topology for TenByTen {
+    type Square = Square50;
+
+    const SIZE: u8 = 10;
+
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        Grid::step(cell, direction)
+    }
+
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        Grid::shift(cells, direction)
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        Grid::cell(number)
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        Grid::number(cell)
+    }
+
+    fn coordinates(cell: MonoBitBoard) -> Option<(u8, u8)> {
+        Grid::coordinates(cell)
+    }
+
+    fn cell_at(row: u8, column: u8) -> Option<MonoBitBoard> {
+        Grid::cell_at(row, column)
+    }
+
+    fn initial_pieces(player: Player) -> BitBoard {
+        match player {
+            Player::Red => Grid::rows(6..10),
+            Player::Black => Grid::rows(0..4),
+        }
+    }
+
+    fn promotion_squares(player: Player) -> BitBoard {
+        match player {
+            Player::Red => Grid::rows(0..1),
+            Player::Black => Grid::rows(9..10),
+        }
+    }
+}
+
+/// Rules of international draughts, played on the [TenByTen] board. Red takes the role of white
+/// and moves first, men capture backwards, kings fly, the turn capturing the most pieces must be
+/// taken and men are only crowned when ending their turn on the king row.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct InternationalRules;
+
+impl Rules for InternationalRules {
+    type Topology = TenByTen;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        true
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        true
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::ContinuesAsMan
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(20)
+    }
+}
@@ -6,5 +6,14 @@ extern crate num_derive;
 
 pub mod bitboard;
 pub mod board;
+pub mod canadian;
+pub mod fen;
+pub mod frisian;
+pub mod international;
+pub mod pdn;
+pub mod perft;
 pub mod position;
+pub mod rules;
+pub mod topology;
+pub mod turkish;
 pub mod turn;
@@ -0,0 +1,833 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Peekable;
+use std::str::Chars;
+
+use thiserror::Error;
+
+use crate::board::{Board, BoardStatus, Player};
+use crate::canadian::CanadianRules;
+use crate::fen::FenError;
+use crate::frisian::FrisianRules;
+use crate::international::InternationalRules;
+use crate::position::{Move, MoveError, NotationError};
+use crate::rules::{
+    BrazilianRules, EnglishRules, ItalianRules, PoolRules, Rules, RussianRules, SpanishRules,
+};
+use crate::topology::Topology;
+use crate::turkish::TurkishRules;
+use crate::turn::Turn;
+
+/// Error that can occur while reading a game written in Portable Draughts Notation, or while
+/// replaying it. The line and column point at the place in the text the error was found at, both
+/// counting from one.
+#[derive(Debug, Error, PartialEq)]
+#[error("Line {line}, column {column}: {kind}")]
+pub struct PdnError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: PdnErrorKind,
+}
+
+/// Describes what went wrong while reading or replaying a PDN game.
+#[derive(Debug, Error, PartialEq)]
+pub enum PdnErrorKind {
+    #[error("Unexpected character '{0}'.")]
+    UnexpectedCharacter(char),
+
+    #[error("Unexpected end of the game text.")]
+    UnexpectedEnd,
+
+    #[error("Tag pairs must consist of a name followed by a quoted value between brackets.")]
+    InvalidTag,
+
+    #[error("Comment was never closed.")]
+    UnclosedComment,
+
+    #[error("Variation was never closed.")]
+    UnclosedVariation,
+
+    #[error("Variations and annotations must follow the turn they belong to.")]
+    MissingTurn,
+
+    #[error("'{0}' is not a valid annotation.")]
+    InvalidAnnotation(String),
+
+    #[error("'{0}' is not a valid turn.")]
+    InvalidTurn(String),
+
+    #[error("Turn notation could not be read. {0}")]
+    Notation(NotationError),
+
+    #[error("Turn could not be played. {0}")]
+    IllegalTurn(MoveError),
+
+    #[error("Position the game starts from could not be read. {0}")]
+    Fen(FenError),
+
+    #[error("'{0}' is not a supported game type.")]
+    UnsupportedGameType(String),
+}
+
+/// The outcome of a game as recorded at the end of its move text. Results are written from the
+/// point of view of the white player, which is the role of red on this board.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum GameResult {
+    Win(Player),
+    Draw,
+    /// The game is still in progress, was abandoned or its result is otherwise unknown.
+    #[default]
+    Unknown,
+}
+
+/// A board replayed from a game, played according to the rules named by the game's GameType tag.
+/// Portuguese games are played on a board following [SpanishRules], which describe both variants.
+#[derive(Debug)]
+pub enum VariantBoard {
+    English(Board<EnglishRules>),
+    International(Board<InternationalRules>),
+    Italian(Board<ItalianRules>),
+    Pool(Board<PoolRules>),
+    Spanish(Board<SpanishRules>),
+    Russian(Board<RussianRules>),
+    Brazilian(Board<BrazilianRules>),
+    Canadian(Board<CanadianRules>),
+    Turkish(Board<TurkishRules>),
+    Frisian(Board<FrisianRules>),
+}
+
+/// A single turn of a game's move text along with the annotations that surround it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameTurn {
+    squares: Vec<u8>,
+    capture: bool,
+    nags: Vec<u8>,
+    preceding_comments: Vec<String>,
+    comments: Vec<String>,
+    variations: Vec<Vec<GameTurn>>,
+    line: usize,
+    column: usize,
+}
+
+impl GameTurn {
+    /// Returns the numbers of the squares the moving piece visits, starting with the square it
+    /// moves from.
+    pub fn squares(&self) -> &[u8] {
+        &self.squares
+    }
+
+    /// Returns true if the turn was written as a capture.
+    pub fn is_capture(&self) -> bool {
+        self.capture
+    }
+
+    /// Returns the numeric annotation glyphs given to the turn. Move strength symbols such as `!`
+    /// and `?!` are converted into their numeric counterparts.
+    pub fn nags(&self) -> &[u8] {
+        &self.nags
+    }
+
+    /// Returns the comments written before the turn. Only the first turn of a variation can be
+    /// preceded by comments, comments before the first turn of the game belonging to the game.
+    pub fn preceding_comments(&self) -> &[String] {
+        &self.preceding_comments
+    }
+
+    /// Returns the comments following the turn.
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    /// Returns the alternatives to this turn. Each variation starts with a turn that could have
+    /// been played instead of this one.
+    pub fn variations(&self) -> &[Vec<GameTurn>] {
+        &self.variations
+    }
+
+    /// Returns the line and column the turn was written at, both being zero for turns that were
+    /// not read from text.
+    pub fn position(&self) -> (usize, usize) {
+        (self.line, self.column)
+    }
+
+    /// Returns the turn written in checkers notation, listing every square that is visited.
+    pub fn notation(&self) -> String {
+        let separator = if self.capture { "x" } else { "-" };
+        self.squares
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<String>>()
+            .join(separator)
+    }
+
+    /// Converts the turn into a [Turn] played on the given topology. Every square visited is
+    /// reached with a separate move, so captures written in shortened form need to be resolved
+    /// against a board with [Turn::resolve] instead.
+    pub fn turn<T: Topology>(&self) -> Result<Turn<T>, NotationError> {
+        Turn::parse(&self.notation())
+    }
+
+    fn error(&self, kind: PdnErrorKind) -> PdnError {
+        PdnError {
+            line: self.line,
+            column: self.column,
+            kind,
+        }
+    }
+}
+
+/// Converts a turn played on a board into a turn of a game's move text.
+impl<T: Topology> From<&Turn<T>> for GameTurn {
+    fn from(turn: &Turn<T>) -> Self {
+        let moves = turn.moves();
+        let cells = moves
+            .first()
+            .map(Move::source)
+            .into_iter()
+            .chain(moves.iter().map(Move::destination));
+
+        GameTurn {
+            squares: cells.filter_map(T::number).collect(),
+            capture: moves.iter().any(|m| m.capture().is_some()),
+            nags: vec![],
+            preceding_comments: vec![],
+            comments: vec![],
+            variations: vec![],
+            line: 0,
+            column: 0,
+        }
+    }
+}
+
+/// A game written in Portable Draughts Notation. Games consist of tag pairs describing the game,
+/// the turns played, and the result the game ended with. Games are written as PDN text through
+/// their [Display] implementation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Game {
+    tags: Vec<(String, String)>,
+    tag_positions: Vec<(usize, usize)>,
+    comments: Vec<String>,
+    turns: Vec<GameTurn>,
+    first_ply: usize,
+    result: GameResult,
+    // Result token as written in the text the game was read from, such as `1-0` rather than `2-0`.
+    result_notation: Option<String>,
+}
+
+impl Game {
+    /// Creates a game from the turns played on the given board. The game is given the tags of the
+    /// seven tag roster, which are left unknown apart from the result, as well as the result
+    /// itself once the board's game has concluded. The GameType tag names the rules of the board
+    /// when they have a [Rules::game_type], and boards that were not set up with the starting
+    /// position of their rules are described by the FEN tag.
+    pub fn from_board<R: Rules>(board: &Board<R>) -> Self {
+        let result = match board.status() {
+            BoardStatus::Complete { winner } => GameResult::Win(winner),
+            BoardStatus::Draw { .. } => GameResult::Draw,
+            BoardStatus::OnGoing => GameResult::Unknown,
+        };
+        let tags = [
+            ("Event", "?"),
+            ("Site", "?"),
+            ("Date", "????.??.??"),
+            ("Round", "?"),
+            ("White", "?"),
+            ("Black", "?"),
+        ];
+
+        let initial_state = board.initial_state();
+        let mut game = Game {
+            turns: board.turns().iter().map(GameTurn::from).collect(),
+            first_ply: (initial_state.current_player != board.rules().first_player()) as usize,
+            result,
+            ..Game::default()
+        };
+        for (name, value) in tags {
+            game.set_tag(name, value);
+        }
+        game.set_tag("Result", &result.to_string());
+        if let Some(game_type) = board.rules().game_type() {
+            game.set_tag("GameType", &game_type.to_string());
+        }
+        if initial_state != Board::with_rules(*board.rules()).initial_state() {
+            game.set_tag("SetUp", "1")
+                .set_tag("FEN", &initial_state.format_fen::<R::Topology>());
+        }
+        game
+    }
+
+    /// Attempts to read a single game from the given PDN text.
+    pub fn parse(text: &str) -> Result<Self, PdnError> {
+        let mut parser = Parser::new(text);
+        let game = parser.game()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            Some(character) => Err(parser.error(PdnErrorKind::UnexpectedCharacter(character))),
+            None => Ok(game),
+        }
+    }
+
+    /// Attempts to read every game from the given PDN text, such as the contents of a PDN file.
+    pub fn parse_all(text: &str) -> Result<Vec<Self>, PdnError> {
+        let mut parser = Parser::new(text);
+        let mut games = vec![];
+        loop {
+            parser.skip_whitespace();
+            if parser.peek().is_none() {
+                return Ok(games);
+            }
+            games.push(parser.game()?);
+        }
+    }
+
+    /// Returns every tag pair of the game in the order they were written in.
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    /// Returns the value of the tag with the given name, if the game has such a tag.
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets the value of the tag with the given name, replacing the tag if the game already has
+    /// one and adding it after the existing tags otherwise.
+    pub fn set_tag(&mut self, name: &str, value: &str) -> &mut Self {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => {
+                self.tags.push((name.to_string(), value.to_string()));
+                self.tag_positions.push((0, 0));
+            }
+        }
+        self
+    }
+
+    /// Returns the comments written before the first turn of the game.
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
+    /// Returns the turns of the main line of the game.
+    pub fn turns(&self) -> &[GameTurn] {
+        &self.turns
+    }
+
+    /// Returns the result the game ended with.
+    pub fn result(&self) -> GameResult {
+        self.result
+    }
+
+    /// Returns the number of the variant the game was played in, as named by the GameType tag.
+    /// Games without the tag are English checkers games. Any details following the number, such
+    /// as the board size, are ignored.
+    pub fn game_type(&self) -> Result<u8, PdnError> {
+        let Some(index) = self.tags.iter().position(|(name, _)| name == "GameType") else {
+            return Ok(EnglishRules.game_type().unwrap_or_default());
+        };
+        let value = &self.tags[index].1;
+        let number = value.split(',').next().unwrap_or_default().trim();
+        number.parse().map_err(|_| {
+            let (line, column) = self.tag_positions[index];
+            PdnError {
+                line,
+                column,
+                kind: PdnErrorKind::UnsupportedGameType(value.clone()),
+            }
+        })
+    }
+
+    /// Replays the main line of the game on a new board played according to the rules named by
+    /// the game's [Game::game_type]. The board is set up as described by [Game::setup_with_rules].
+    pub fn variant_board(&self) -> Result<VariantBoard, PdnError> {
+        let game_type = self.game_type()?;
+        let board = match game_type {
+            20 => VariantBoard::International(self.board_with_rules(InternationalRules)?),
+            21 => VariantBoard::English(self.board_with_rules(EnglishRules)?),
+            22 => VariantBoard::Italian(self.board_with_rules(ItalianRules)?),
+            23 => VariantBoard::Pool(self.board_with_rules(PoolRules)?),
+            24 | 28 => VariantBoard::Spanish(self.board_with_rules(SpanishRules)?),
+            25 => VariantBoard::Russian(self.board_with_rules(RussianRules)?),
+            26 => VariantBoard::Brazilian(self.board_with_rules(BrazilianRules)?),
+            27 => VariantBoard::Canadian(self.board_with_rules(CanadianRules)?),
+            30 => VariantBoard::Turkish(self.board_with_rules(TurkishRules)?),
+            40 => VariantBoard::Frisian(self.board_with_rules(FrisianRules)?),
+            _ => {
+                let index = self.tags.iter().position(|(name, _)| name == "GameType");
+                let (line, column) = index.map_or((0, 0), |index| self.tag_positions[index]);
+                return Err(PdnError {
+                    line,
+                    column,
+                    kind: PdnErrorKind::UnsupportedGameType(game_type.to_string()),
+                });
+            }
+        };
+        Ok(board)
+    }
+
+    /// Replays the main line of the game on a new board played according to [EnglishRules],
+    /// regardless of the GameType tag. See [Game::variant_board] to follow the tag instead.
+    pub fn board(&self) -> Result<Board, PdnError> {
+        self.board_with_rules(EnglishRules)
+    }
+
+    /// Replays the main line of the game on a new board played according to the given rules. The
+    /// board is set up as described by [Game::setup_with_rules].
+    pub fn board_with_rules<R: Rules>(&self, rules: R) -> Result<Board<R>, PdnError> {
+        let mut board = self.setup_with_rules(rules)?;
+        self.replay(&mut board)?;
+        Ok(board)
+    }
+
+    /// Creates the board the game starts on, played according to [EnglishRules], without playing
+    /// any of its turns.
+    pub fn setup(&self) -> Result<Board, PdnError> {
+        self.setup_with_rules(EnglishRules)
+    }
+
+    /// Creates the board the game starts on, played according to the given rules, without playing
+    /// any of its turns. The board is set up with the position of the FEN tag if the game has one,
+    /// and with the starting position of the rules otherwise. Settings such as the draw turn limit
+    /// can be changed on the board before passing it to [Game::replay].
+    pub fn setup_with_rules<R: Rules>(&self, rules: R) -> Result<Board<R>, PdnError> {
+        let Some(index) = self.tags.iter().position(|(name, _)| name == "FEN") else {
+            return Ok(Board::with_rules(rules));
+        };
+        let (line, column) = self.tag_positions[index];
+        Board::from_fen_with_rules(&self.tags[index].1, rules).map_err(|error| PdnError {
+            line,
+            column,
+            kind: PdnErrorKind::Fen(error),
+        })
+    }
+
+    /// Plays every turn of the main line of the game on the given board with
+    /// [Board::push_turn], regardless of the tags of the game. Captures are resolved against the
+    /// board, so that they may be written in shortened form. The draw turn limit of the board
+    /// applies, so a game played under other draw rules may need the limit changed with
+    /// [Board::set_draw_turn_limit] first. Errors point at the turn that could not be played.
+    pub fn replay<R: Rules>(&self, board: &mut Board<R>) -> Result<(), PdnError> {
+        for game_turn in &self.turns {
+            let turn = match game_turn.capture {
+                true => Turn::resolve(&game_turn.notation(), board.current_state(), *board.rules()),
+                false => game_turn.turn::<R::Topology>(),
+            };
+            let turn = turn.map_err(|error| game_turn.error(PdnErrorKind::Notation(error)))?;
+            board
+                .push_turn(turn)
+                .map_err(|error| game_turn.error(PdnErrorKind::IllegalTurn(error)))?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the game as PDN text. Tag pairs are written one per line, followed by an empty line and
+/// the move text, which is wrapped to fit lines of 80 characters and ends with the result. Games
+/// read from PDN text keep the notation their result was written in.
+impl Display for Game {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (name, value) in &self.tags {
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(f, "[{name} \"{value}\"]")?;
+        }
+        if !self.tags.is_empty() {
+            writeln!(f)?;
+        }
+
+        let mut tokens = self
+            .comments
+            .iter()
+            .map(|comment| format!("{{{comment}}}"))
+            .collect();
+        write_turns(&mut tokens, &self.turns, self.first_ply);
+        match &self.result_notation {
+            Some(notation) => tokens.push(notation.clone()),
+            None => tokens.push(self.result.to_string()),
+        }
+
+        let mut length = 0;
+        for token in tokens {
+            if length > 0 && length + token.len() + 1 > MAX_LINE_LENGTH {
+                writeln!(f)?;
+                length = 0;
+            }
+            if length > 0 {
+                write!(f, " ")?;
+                length += 1;
+            }
+            write!(f, "{token}")?;
+            length += token.len();
+        }
+        writeln!(f)
+    }
+}
+
+/// Allows PDN text of a single game to be easily converted into game instances.
+impl TryFrom<&str> for Game {
+    type Error = PdnError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Game::parse(value)
+    }
+}
+
+/// Writes the result as the result token ending the move text of a game.
+impl Display for GameResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let token = match self {
+            GameResult::Win(Player::Red) => "2-0",
+            GameResult::Win(Player::Black) => "0-2",
+            GameResult::Draw => "1-1",
+            GameResult::Unknown => "*",
+        };
+        write!(f, "{token}")
+    }
+}
+
+// Longest line written in the move text of a game, unless a single token is longer.
+const MAX_LINE_LENGTH: usize = 80;
+
+// Appends the tokens of the given line of play, its first turn being the given ply of the game.
+// Turns of the first player are preceded by their move number, as are turns of the second player
+// that follow a comment or variation.
+fn write_turns(tokens: &mut Vec<String>, turns: &[GameTurn], ply: usize) {
+    let mut numbered = false;
+    for (index, turn) in turns.iter().enumerate() {
+        let ply = ply + index;
+        let number = ply / 2 + 1;
+        let comments = turn.preceding_comments.iter();
+        tokens.extend(comments.map(|comment| format!("{{{comment}}}")));
+        numbered = numbered && turn.preceding_comments.is_empty();
+        match ply % 2 {
+            0 => tokens.push(format!("{number}.")),
+            _ if !numbered => tokens.push(format!("{number}...")),
+            _ => {}
+        }
+
+        tokens.push(turn.notation());
+        tokens.extend(turn.nags.iter().map(|nag| format!("${nag}")));
+        tokens.extend(turn.comments.iter().map(|comment| format!("{{{comment}}}")));
+        for variation in &turn.variations {
+            let mut variation_tokens = vec![];
+            write_turns(&mut variation_tokens, variation, ply);
+            if let Some(first) = variation_tokens.first_mut() {
+                first.insert(0, '(');
+            }
+            if let Some(last) = variation_tokens.last_mut() {
+                last.push(')');
+            }
+            tokens.append(&mut variation_tokens);
+        }
+        numbered = turn.comments.is_empty() && turn.variations.is_empty();
+    }
+}
+
+// The turns of a line of play, which is either the main line of a game or a variation.
+#[derive(Default)]
+struct Line {
+    comments: Vec<String>,
+    turns: Vec<GameTurn>,
+    first_ply: usize,
+    result: Option<GameResult>,
+    result_notation: Option<String>,
+}
+
+// Reads PDN text one character at a time, keeping track of the position in the text for error
+// reporting.
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Parser {
+            chars: text.chars().peekable(),
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn game(&mut self) -> Result<Game, PdnError> {
+        let mut tags = vec![];
+        let mut tag_positions = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('[') {
+                break;
+            }
+            tag_positions.push((self.line, self.column));
+            tags.push(self.tag()?);
+        }
+
+        let line = self.line(None)?;
+        Ok(Game {
+            tags,
+            tag_positions,
+            comments: line.comments,
+            turns: line.turns,
+            first_ply: line.first_ply,
+            result: line.result.unwrap_or_default(),
+            result_notation: line.result_notation,
+        })
+    }
+
+    fn tag(&mut self) -> Result<(String, String), PdnError> {
+        self.bump();
+        self.skip_whitespace();
+        let name =
+            self.take_while(|character| character.is_ascii_alphanumeric() || character == '_');
+        if name.is_empty() {
+            return Err(self.error(PdnErrorKind::InvalidTag));
+        }
+        self.skip_whitespace();
+        if self.peek() != Some('"') {
+            return Err(self.error(PdnErrorKind::InvalidTag));
+        }
+        self.bump();
+
+        let mut value = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => match self.bump() {
+                    Some(character) => value.push(character),
+                    None => return Err(self.error(PdnErrorKind::UnexpectedEnd)),
+                },
+                Some(character) => value.push(character),
+                None => return Err(self.error(PdnErrorKind::UnexpectedEnd)),
+            }
+        }
+
+        self.skip_whitespace();
+        match self.bump() {
+            Some(']') => Ok((name, value)),
+            _ => Err(self.error(PdnErrorKind::InvalidTag)),
+        }
+    }
+
+    // Reads turns until the line ends. The main line ends with a result, with the tags of the next
+    // game or with the end of the text, while variations opened at the given position end with a
+    // closing parenthesis.
+    fn line(&mut self, opening: Option<(usize, usize)>) -> Result<Line, PdnError> {
+        let variation = opening.is_some();
+        let mut line = Line::default();
+        loop {
+            self.skip_whitespace();
+            let (start_line, start_column) = (self.line, self.column);
+            match self.peek() {
+                None => {
+                    return match opening {
+                        Some((line, column)) => {
+                            Err(self.error_at(line, column, PdnErrorKind::UnclosedVariation))
+                        }
+                        None => Ok(line),
+                    }
+                }
+                Some('[') if opening.is_none() => return Ok(line),
+                Some(')') if opening.is_some() => {
+                    self.bump();
+                    return Ok(line);
+                }
+                Some('{') => {
+                    let comment = self.comment()?;
+                    match line.turns.last_mut() {
+                        Some(turn) => turn.comments.push(comment),
+                        None => line.comments.push(comment),
+                    }
+                }
+                Some(';') => {
+                    self.bump();
+                    let comment = self.take_while(|character| character != '\n');
+                    match line.turns.last_mut() {
+                        Some(turn) => turn.comments.push(comment.trim().to_string()),
+                        None => line.comments.push(comment.trim().to_string()),
+                    }
+                }
+                Some('(') => {
+                    self.bump();
+                    let mut variation = self.line(Some((start_line, start_column)))?;
+                    let Some(turn) = line.turns.last_mut() else {
+                        return Err(self.error_at(
+                            start_line,
+                            start_column,
+                            PdnErrorKind::MissingTurn,
+                        ));
+                    };
+                    if let Some(first) = variation.turns.first_mut() {
+                        first.preceding_comments = variation.comments;
+                    }
+                    turn.variations.push(variation.turns);
+                }
+                Some('$' | '!' | '?') => {
+                    let nag = self.nag()?;
+                    let Some(turn) = line.turns.last_mut() else {
+                        return Err(self.error_at(
+                            start_line,
+                            start_column,
+                            PdnErrorKind::MissingTurn,
+                        ));
+                    };
+                    turn.nags.push(nag);
+                }
+                Some('*') => {
+                    self.bump();
+                    line.result = Some(GameResult::Unknown);
+                    if !variation {
+                        return Ok(line);
+                    }
+                }
+                Some(character) if character.is_ascii_digit() => {
+                    let number = self.take_while(|character| character.is_ascii_digit());
+                    if self.peek() == Some('.') {
+                        // Move numbers only matter for telling which player makes the first turn
+                        // of the line, a number followed by an ellipsis being the second player's.
+                        let periods = self.take_while(|character| character == '.');
+                        if let (true, Ok(number)) = (line.turns.is_empty(), number.parse::<usize>())
+                        {
+                            line.first_ply =
+                                number.saturating_sub(1) * 2 + (periods.len() > 1) as usize;
+                        }
+                        continue;
+                    }
+
+                    let rest = self.take_while(|character| {
+                        character.is_ascii_digit() || matches!(character, '-' | 'x' | 'X' | '/')
+                    });
+                    let word = number + &rest;
+                    if let Some(result) = parse_result(&word) {
+                        line.result = Some(result);
+                        line.result_notation = Some(word);
+                        if !variation {
+                            return Ok(line);
+                        }
+                        continue;
+                    }
+
+                    let turn = parse_turn(&word, start_line, start_column).ok_or_else(|| {
+                        self.error_at(start_line, start_column, PdnErrorKind::InvalidTurn(word))
+                    })?;
+                    line.turns.push(turn);
+                }
+                Some(character) => {
+                    return Err(self.error(PdnErrorKind::UnexpectedCharacter(character)))
+                }
+            }
+        }
+    }
+
+    fn comment(&mut self) -> Result<String, PdnError> {
+        let (line, column) = (self.line, self.column);
+        self.bump();
+        let comment = self.take_while(|character| character != '}');
+        match self.bump() {
+            Some(_) => Ok(comment.trim().to_string()),
+            None => Err(self.error_at(line, column, PdnErrorKind::UnclosedComment)),
+        }
+    }
+
+    fn nag(&mut self) -> Result<u8, PdnError> {
+        let (line, column) = (self.line, self.column);
+        let annotation = match self.bump() {
+            Some('$') => {
+                let number = self.take_while(|character| character.is_ascii_digit());
+                return number.parse().map_err(|_| {
+                    self.error_at(
+                        line,
+                        column,
+                        PdnErrorKind::InvalidAnnotation(format!("${number}")),
+                    )
+                });
+            }
+            Some(character) => {
+                let rest = self.take_while(|character| matches!(character, '!' | '?'));
+                format!("{character}{rest}")
+            }
+            None => return Err(self.error(PdnErrorKind::UnexpectedEnd)),
+        };
+
+        match annotation.as_str() {
+            "!" => Ok(1),
+            "?" => Ok(2),
+            "!!" => Ok(3),
+            "??" => Ok(4),
+            "!?" => Ok(5),
+            "?!" => Ok(6),
+            _ => Err(self.error_at(line, column, PdnErrorKind::InvalidAnnotation(annotation))),
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let character = self.chars.next()?;
+        match character {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            _ => self.column += 1,
+        }
+        Some(character)
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> String {
+        let mut taken = String::new();
+        while let Some(character) = self.peek().filter(|character| predicate(*character)) {
+            taken.push(character);
+            self.bump();
+        }
+        taken
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn error(&self, kind: PdnErrorKind) -> PdnError {
+        self.error_at(self.line, self.column, kind)
+    }
+
+    fn error_at(&self, line: usize, column: usize, kind: PdnErrorKind) -> PdnError {
+        PdnError { line, column, kind }
+    }
+}
+
+fn parse_result(word: &str) -> Option<GameResult> {
+    match word {
+        "2-0" | "1-0" => Some(GameResult::Win(Player::Red)),
+        "0-2" | "0-1" => Some(GameResult::Win(Player::Black)),
+        "1-1" | "1/2-1/2" => Some(GameResult::Draw),
+        _ => None,
+    }
+}
+
+// Reads a turn written as squares separated by dashes for a quiet move or by crosses for a
+// capture. Captures may visit any amount of squares.
+fn parse_turn(word: &str, line: usize, column: usize) -> Option<GameTurn> {
+    let capture = word.contains(['x', 'X']);
+    let squares = word
+        .split(['-', 'x', 'X'])
+        .map(|square| square.parse::<u8>().ok().filter(|square| *square > 0))
+        .collect::<Option<Vec<u8>>>()?;
+    let valid_length = match capture {
+        true => squares.len() >= 2 && !word.contains('-'),
+        false => squares.len() == 2,
+    };
+
+    valid_length.then_some(GameTurn {
+        squares,
+        capture,
+        nags: vec![],
+        preceding_comments: vec![],
+        comments: vec![],
+        variations: vec![],
+        line,
+        column,
+    })
+}
@@ -0,0 +1,55 @@
+use crate::board::BoardState;
+use crate::rules::{EnglishRules, Rules};
+use crate::turn::{LegalTurnIter, Turn};
+
+/// Counts the leaf nodes of the game tree of the given depth that is rooted at the given board
+/// state, playing according to [EnglishRules]. Every complete turn counts as a single ply,
+/// regardless of the amount of jumps it is made up of.
+pub fn perft(board_state: &BoardState, depth: usize) -> u64 {
+    perft_with_rules(board_state, depth, EnglishRules)
+}
+
+/// Counts the leaf nodes of the game tree of the given depth that is rooted at the given board
+/// state, playing according to the given rules. Rules depending on the history of a game, such
+/// as a king move limit, are not taken into account as a board state carries no history.
+pub fn perft_with_rules<R: Rules>(board_state: &BoardState, depth: usize, rules: R) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let successors = LegalTurnIter::with_rules(board_state, rules).successors();
+    if depth == 1 {
+        return successors.len() as u64;
+    }
+    successors
+        .map(|(_, board_state)| perft_with_rules(&board_state, depth - 1, rules))
+        .sum()
+}
+
+/// Breaks the [perft] count of the given depth down by the legal turns available in the given
+/// board state, playing according to [EnglishRules]. Turns are listed in the order they are
+/// generated in, each alongside the leaf nodes found below it.
+pub fn divide(board_state: &BoardState, depth: usize) -> Vec<(Turn, u64)> {
+    divide_with_rules(board_state, depth, EnglishRules)
+}
+
+/// Breaks the [perft_with_rules] count of the given depth down by the legal turns available in
+/// the given board state, playing according to the given rules. A depth of zero has no turns to
+/// break the count down by.
+pub fn divide_with_rules<R: Rules>(
+    board_state: &BoardState,
+    depth: usize,
+    rules: R,
+) -> Vec<(Turn<R::Topology>, u64)> {
+    if depth == 0 {
+        return vec![];
+    }
+
+    LegalTurnIter::with_rules(board_state, rules)
+        .successors()
+        .map(|(turn, board_state)| {
+            let nodes = perft_with_rules(&board_state, depth - 1, rules);
+            (turn, nodes)
+        })
+        .collect()
+}
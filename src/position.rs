@@ -1,11 +1,14 @@
+use std::cell::OnceCell;
 use std::convert::Infallible;
 
 use lazy_static::lazy_static;
 use regex::{Captures, Regex};
 use thiserror::Error;
 
-use crate::bitboard::{BitBoard, CellIter, MonoBitBoard};
+use crate::bitboard::{BitBoard, MonoBitBoard};
 use crate::board::{BoardState, Player};
+use crate::rules::{EnglishRules, Rules};
+use crate::topology::{Direction, EightByEight, Topology};
 
 /// Error denoting an issue parsing checkers notation.
 #[derive(Debug, Error, PartialEq)]
@@ -13,168 +16,336 @@ pub enum NotationError {
     #[error("Provided value did not conform to a valid checkers notation format.")]
     InvalidFormat,
 
-    #[error("Provided value operates outside the realm of a classical checkers board.")]
+    #[error("Provided value operates outside the squares of the board being played on.")]
     OutOfRange,
+
+    #[error("Provided value does not describe any legal turn of the board being played on.")]
+    NoLegalTurn,
+
+    #[error("Provided value describes more than one legal turn. Include the squares visited along the way to tell them apart.")]
+    AmbiguousTurn,
 }
 
 #[derive(Debug, Error)]
 #[error("Square could not be converted")]
 pub struct SquareConversionError;
 
-/// Represents every valid square on a classical checkers board.
-#[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq)]
-pub enum Square {
-    One = 1,
-    Two = 2,
-    Three = 3,
-    Four = 4,
-    Five = 5,
-    Six = 6,
-    Seven = 7,
-    Eight = 8,
-    Nine = 9,
-    Ten = 10,
-    Eleven = 11,
-    Twelve = 12,
-    Thirteen = 13,
-    Fourteen = 14,
-    Fifteen = 15,
-    Sixteen = 16,
-    Seventeen = 17,
-    Eighteen = 18,
-    Nineteen = 19,
-    Twenty = 20,
-    TwentyOne = 21,
-    TwentyTwo = 22,
-    TwentyThree = 23,
-    TwentyFour = 24,
-    TwentyFive = 25,
-    TwentySix = 26,
-    TwentySeven = 27,
-    TwentyEight = 28,
-    TwentyNine = 29,
-    Thirty = 30,
-    ThirtyOne = 31,
-    ThirtyTwo = 32,
+// Defines a square type whose variants name squares numbered from 1, along with conversions from
+// and to square numbers. Naming the topology the squares belong to also adds conversions from and
+// to its cells and moves between its squares.
+macro_rules! square_type {
+    ($(#[$meta:meta])* $name:ident => [$first:ident, $($rest:ident),* $(,)?]) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, FromPrimitive, ToPrimitive, PartialEq)]
+        pub enum $name {
+            $first = 1,
+            $($rest,)*
+        }
+
+        impl $name {
+            const ALL: &'static [$name] = &[$name::$first, $($name::$rest,)*];
+
+            /// Provides an iterator over all square values.
+            pub fn iter() -> impl Iterator<Item = Self> {
+                $name::ALL.iter().copied()
+            }
+
+            /// Returns the square instance represented as a u8.
+            pub fn to_number(&self) -> u8 {
+                num::ToPrimitive::to_u8(self).unwrap()
+            }
+        }
+
+        impl From<$name> for u8 {
+            #[doc = concat!("Converts a [", stringify!($name), "] into its square number.")]
+            fn from(square: $name) -> Self {
+                square.to_number()
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = $crate::position::NotationError;
+
+            /// Converts a number to its square representation.
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                num::FromPrimitive::from_u8(value)
+                    .ok_or($crate::position::NotationError::OutOfRange)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = $crate::position::NotationError;
+
+            /// Converts a number in string format to its square representation.
+            fn try_from(text: &str) -> Result<Self, Self::Error> {
+                let value = text
+                    .parse::<u8>()
+                    .map_err(|_| $crate::position::NotationError::InvalidFormat)?;
+                $name::try_from(value)
+            }
+        }
+    };
+    ($(#[$meta:meta])* $name:ident, $topology:ident => [$($variant:ident),* $(,)?]) => {
+        square_type! {
+            $(#[$meta])*
+            $name => [$($variant),*]
+        }
+
+        impl From<$name> for $crate::bitboard::MonoBitBoard {
+            fn from(square: $name) -> Self {
+                // Every square names one of the cells of its topology.
+                <$topology as $crate::topology::Topology>::cell(square.to_number()).unwrap()
+            }
+        }
+
+        impl TryFrom<$crate::bitboard::MonoBitBoard> for $name {
+            type Error = $crate::position::SquareConversionError;
+
+            #[doc = concat!(
+                "Converts the given [MonoBitBoard](crate::bitboard::MonoBitBoard) to a [",
+                stringify!($name),
+                "] instance. Results in an error when the bitboard does not represent a square of ",
+                "the [",
+                stringify!($topology),
+                "] board."
+            )]
+            fn try_from(bitboard: $crate::bitboard::MonoBitBoard) -> Result<Self, Self::Error> {
+                <$topology as $crate::topology::Topology>::number(bitboard)
+                    .and_then(|number| $name::try_from(number).ok())
+                    .ok_or($crate::position::SquareConversionError)
+            }
+        }
+
+        // Kept fallible so that the conversions of every square type match those of the
+        // original [Square](crate::position::Square).
+        #[allow(clippy::infallible_try_from)]
+        impl TryFrom<($name, $name)> for $crate::position::Move {
+            type Error = std::convert::Infallible;
+
+            #[doc = concat!("Converts a tuple of [", stringify!($name), "] into a [Move](crate::position::Move) instance.")]
+            fn try_from(value: ($name, $name)) -> Result<Self, Self::Error> {
+                let source = $crate::bitboard::MonoBitBoard::from(value.0);
+                let destination = $crate::bitboard::MonoBitBoard::from(value.1);
+                Ok($crate::position::Move::on::<$topology>(source, destination))
+            }
+        }
+    };
+}
+
+pub(crate) use square_type;
+
+square_type! {
+    /// Represents every valid square on the classical 8x8 checkers board of [EightByEight]. Boards
+    /// of other topologies name their squares with their own types, such as
+    /// [Square50](crate::international::Square50).
+    Square, EightByEight => [
+        One,
+        Two,
+        Three,
+        Four,
+        Five,
+        Six,
+        Seven,
+        Eight,
+        Nine,
+        Ten,
+        Eleven,
+        Twelve,
+        Thirteen,
+        Fourteen,
+        Fifteen,
+        Sixteen,
+        Seventeen,
+        Eighteen,
+        Nineteen,
+        Twenty,
+        TwentyOne,
+        TwentyTwo,
+        TwentyThree,
+        TwentyFour,
+        TwentyFive,
+        TwentySix,
+        TwentySeven,
+        TwentyEight,
+        TwentyNine,
+        Thirty,
+        ThirtyOne,
+        ThirtyTwo,
+    ]
 }
 
 impl Square {
-    /// Provides an iterator over all square values.
-    pub fn iter() -> impl Iterator<Item = Self> {
-        [
-            Square::One,
-            Square::Two,
-            Square::Three,
-            Square::Four,
-            Square::Five,
-            Square::Six,
-            Square::Seven,
-            Square::Eight,
-            Square::Nine,
-            Square::Ten,
-            Square::Eleven,
-            Square::Twelve,
-            Square::Thirteen,
-            Square::Fourteen,
-            Square::Fifteen,
-            Square::Sixteen,
-            Square::Seventeen,
-            Square::Eighteen,
-            Square::Nineteen,
-            Square::Twenty,
-            Square::TwentyOne,
-            Square::TwentyTwo,
-            Square::TwentyThree,
-            Square::TwentyFour,
-            Square::TwentyFive,
-            Square::TwentySix,
-            Square::TwentySeven,
-            Square::TwentyEight,
-            Square::TwentyNine,
-            Square::Thirty,
-            Square::ThirtyOne,
-            Square::ThirtyTwo,
-        ]
-        .iter()
-        .copied()
+    /// Retrieves the index of the bit representing this square on the classical board.
+    pub const fn index(&self) -> u8 {
+        let position = *self as u8 - 1;
+        let row = position / 4;
+        let column = 2 * (position % 4) + (row + 1) % 2;
+        63 - (8 * row + column)
     }
 
-    /// Returns the square instance represented as a u8.
-    pub fn to_number(&self) -> u8 {
-        num::ToPrimitive::to_u8(self).unwrap()
+    /// Retrieves the square represented by the bit of the given index on the classical board.
+    /// Results in an error when the bit does not represent one of the 32 classical squares.
+    pub const fn from_index(index: u8) -> Result<Self, SquareConversionError> {
+        if index >= 64 {
+            return Err(SquareConversionError);
+        }
+        match SQUARES_BY_INDEX[index as usize] {
+            Some(square) => Ok(square),
+            None => Err(SquareConversionError),
+        }
     }
-}
 
-impl From<Square> for MonoBitBoard {
-    fn from(value: Square) -> Self {
-        let value = match value {
-            Square::One => 0x4000000000000000,
-            Square::Two => 0x1000000000000000,
-            Square::Three => 0x400000000000000,
-            Square::Four => 0x100000000000000,
-            Square::Five => 0x80000000000000,
-            Square::Six => 0x20000000000000,
-            Square::Seven => 0x8000000000000,
-            Square::Eight => 0x2000000000000,
-            Square::Nine => 0x400000000000,
-            Square::Ten => 0x100000000000,
-            Square::Eleven => 0x40000000000,
-            Square::Twelve => 0x10000000000,
-            Square::Thirteen => 0x8000000000,
-            Square::Fourteen => 0x2000000000,
-            Square::Fifteen => 0x800000000,
-            Square::Sixteen => 0x200000000,
-            Square::Seventeen => 0x40000000,
-            Square::Eighteen => 0x10000000,
-            Square::Nineteen => 0x4000000,
-            Square::Twenty => 0x1000000,
-            Square::TwentyOne => 0x800000,
-            Square::TwentyTwo => 0x200000,
-            Square::TwentyThree => 0x80000,
-            Square::TwentyFour => 0x20000,
-            Square::TwentyFive => 0x4000,
-            Square::TwentySix => 0x1000,
-            Square::TwentySeven => 0x400,
-            Square::TwentyEight => 0x100,
-            Square::TwentyNine => 0x80,
-            Square::Thirty => 0x20,
-            Square::ThirtyOne => 0x8,
-            Square::ThirtyTwo => 0x2,
+    /// Retrieves the row of this square on the classical board, counted from 0 at the top of
+    /// black's side of the board.
+    pub const fn row(&self) -> u8 {
+        (63 - self.index()) / 8
+    }
+
+    /// Retrieves the column of this square on the classical board, counted from 0 at the left of
+    /// the board as seen from red's side.
+    pub const fn column(&self) -> u8 {
+        (63 - self.index()) % 8
+    }
+
+    /// Retrieves the square sitting on the given row and column of the classical board. Results in
+    /// an error when the cell there is not one of the 32 classical squares.
+    pub fn from_coordinates(row: u8, column: u8) -> Result<Self, SquareConversionError> {
+        EightByEight::cell_at(row, column)
+            .ok_or(SquareConversionError)?
+            .square()
+    }
+
+    /// Converts this square to the cell it represents on a board numbered with the given
+    /// [Orientation].
+    pub fn to_cell(&self, orientation: Orientation) -> MonoBitBoard {
+        let index = match orientation {
+            Orientation::Classical => return MonoBitBoard::from(*self),
+            Orientation::Mirrored => self.to_number() - 1,
+            Orientation::MirroredFromRed => 32 - self.to_number(),
         };
-        MonoBitBoard::new(value).unwrap()
+        let row = index / 4;
+        let column = 2 * (index % 4) + row % 2;
+        MonoBitBoard::new(1 << (63 - (8 * row + column))).unwrap()
     }
-}
 
-impl TryFrom<u8> for Square {
-    type Error = NotationError;
+    /// Converts the given cell to the square it represents on a board numbered with the given
+    /// [Orientation]. Results in an error when the cell is not a playable square of the board.
+    pub fn from_cell(
+        cell: MonoBitBoard,
+        orientation: Orientation,
+    ) -> Result<Self, SquareConversionError> {
+        if orientation == Orientation::Classical {
+            return cell.square();
+        }
+        let index = cell.value().trailing_zeros();
+        if index >= 64 {
+            return Err(SquareConversionError);
+        }
 
-    /// Converts a number to its square representation.
-    fn try_from(value: u8) -> Result<Self, Self::Error> {
-        num::FromPrimitive::from_u8(value).ok_or(NotationError::OutOfRange)
+        let position = 63 - index as u8;
+        let (row, column) = (position / 8, position % 8);
+        if column % 2 != row % 2 {
+            return Err(SquareConversionError);
+        }
+        let index = row * 4 + column / 2;
+        let number = match orientation {
+            Orientation::MirroredFromRed => 32 - index,
+            _ => index + 1,
+        };
+        Ok(Square::ALL[number as usize - 1])
     }
 }
 
-impl TryFrom<&str> for Square {
-    type Error = NotationError;
-
-    /// Converts a number in string format to its square representation
-    fn try_from(text: &str) -> Result<Self, Self::Error> {
-        let value = text
-            .parse::<u8>()
-            .map_err(|_| NotationError::InvalidFormat)?;
-        Square::try_from(value)
+// Squares of the classical board by the index of the bit representing them.
+const SQUARES_BY_INDEX: [Option<Square>; 64] = {
+    let mut squares = [None; 64];
+    let mut position = 0;
+    while position < Square::ALL.len() {
+        let square = Square::ALL[position];
+        squares[square.index() as usize] = Some(square);
+        position += 1;
     }
+    squares
+};
+
+/// Describes how the 32 squares of an 8x8 board are laid out and numbered. Variants disagree on
+/// which corner of the board is playable and which side of the board square 1 sits on.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Orientation {
+    /// Square 1 sits on the second column of black's side of the board, the bottom left corner of
+    /// red's side being playable. Used by English checkers and its relatives.
+    Classical,
+
+    /// Square 1 sits in the top left corner of black's side of the board, squares being numbered
+    /// from left to right. Used by Italian draughts.
+    Mirrored,
+
+    /// The mirrored board numbered from red's side, square 1 sitting in the bottom right corner.
+    /// Used by Spanish and Portuguese draughts.
+    MirroredFromRed,
 }
 
-impl TryFrom<MonoBitBoard> for Square {
-    type Error = SquareConversionError;
+/// Describes how squares are written in checkers notation.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Notation {
+    /// Squares are written as their numbers, such as `11-15`.
+    #[default]
+    Numeric,
+
+    /// Squares are written as chess style coordinates, such as `c3-d4`. Columns are lettered from
+    /// the left and rows numbered from the bottom of the board as seen from red's side.
+    Algebraic,
+}
 
-    /// Converts the given [MonoBitBoard] to a [Square] instance. Results in an error when
-    /// the given bitboard did not represent one of the 32 classical checker squares.
-    fn try_from(bitboard: MonoBitBoard) -> Result<Self, Self::Error> {
-        Square::iter()
-            .find(|square| MonoBitBoard::from(*square) == bitboard)
-            .ok_or(SquareConversionError)
+impl Notation {
+    /// Converts a square written in this notation to the cell it represents on the given
+    /// [Topology].
+    pub fn parse_cell<T: Topology>(&self, text: &str) -> Result<MonoBitBoard, NotationError> {
+        lazy_static! {
+            static ref COORDINATE_PATTERN: Regex = Regex::new(r"^([a-z])([1-9][0-9]?)$").unwrap();
+        }
+
+        match self {
+            Notation::Numeric => {
+                let number = text
+                    .parse::<u8>()
+                    .map_err(|_| NotationError::InvalidFormat)?;
+                T::cell(number).ok_or(NotationError::OutOfRange)
+            }
+            Notation::Algebraic => {
+                let captures = COORDINATE_PATTERN
+                    .captures(text)
+                    .ok_or(NotationError::InvalidFormat)?;
+                let column = captures[1].as_bytes()[0] - b'a';
+                let rank = captures[2]
+                    .parse::<u8>()
+                    .map_err(|_| NotationError::InvalidFormat)?;
+                if rank > T::SIZE {
+                    return Err(NotationError::OutOfRange);
+                }
+                T::cell_at(T::SIZE - rank, column).ok_or(NotationError::OutOfRange)
+            }
+        }
+    }
+
+    /// Writes the given cell of a [Topology] in this notation, or None if the cell is not a
+    /// playable square.
+    pub fn format_cell<T: Topology>(&self, cell: MonoBitBoard) -> Option<String> {
+        match self {
+            Notation::Numeric => T::number(cell).map(|number| number.to_string()),
+            Notation::Algebraic => {
+                let (row, column) = T::coordinates(cell)?;
+                Some(format!("{}{}", (b'a' + column) as char, T::SIZE - row))
+            }
+        }
+    }
+}
+
+impl MonoBitBoard {
+    /// Retrieves the [Square] this cell represents on the classical board. Results in an error
+    /// when the cell is not one of the 32 classical checker squares.
+    pub fn square(&self) -> Result<Square, SquareConversionError> {
+        Square::from_index(self.value().trailing_zeros() as u8)
     }
 }
 
@@ -187,38 +358,37 @@ pub struct Move {
 }
 
 impl Move {
-    /// Creates a new [Move] instance from two given [MonoBitBoard]s.
-    /// Move instances have no context of a board or any checkers rules. Moves are simply
-    /// a source and destination. Move validation is expected to be done via other mechanisms.
+    /// Creates a new [Move] instance from two given [MonoBitBoard]s of the classical
+    /// [EightByEight] board. Move instances have no context of a board or any checkers rules.
+    /// Moves are simply a source and destination. Move validation is expected to be done via other
+    /// mechanisms. See [Move::on] for cells of other topologies.
     pub fn new(source: MonoBitBoard, destination: MonoBitBoard) -> Self {
-        let capture = Move::get_capture(source, destination);
+        Move::on::<EightByEight>(source, destination)
+    }
+
+    /// Creates a new [Move] instance from two given [MonoBitBoard]s of the given [Topology]. The
+    /// move captures the piece it jumps when the cells are two squares apart along one of the
+    /// topology's capture directions.
+    pub fn on<T: Topology>(source: MonoBitBoard, destination: MonoBitBoard) -> Self {
         Move {
             source,
             destination,
-            capture,
+            capture: T::capture_between(source, destination),
         }
     }
 
-    fn get_capture(source: MonoBitBoard, destination: MonoBitBoard) -> Option<MonoBitBoard> {
-        let mut distance = 0;
-        let (mut source, destination) = if source > destination {
-            (source, destination)
-        } else {
-            (destination, source)
-        };
-
-        while source != destination {
-            source >>= 1;
-            distance += 1;
-        }
-
-        let is_capture_move = distance > 9;
-        match is_capture_move {
-            true => {
-                source <<= distance / 2;
-                Some(source)
-            }
-            false => None,
+    /// Creates a new [Move] instance from two given [MonoBitBoard]s that captures the piece on
+    /// the given capture [MonoBitBoard]. Useful when the captured piece can not be derived from
+    /// the source and destination alone, such as a capture made by a flying king.
+    pub fn with_capture(
+        source: MonoBitBoard,
+        destination: MonoBitBoard,
+        capture: MonoBitBoard,
+    ) -> Self {
+        Move {
+            source,
+            destination,
+            capture: Some(capture),
         }
     }
 
@@ -233,25 +403,57 @@ impl Move {
 
     /// Create a new [Move] instance using the given checkers notation text.
     pub fn from_notation(text: &str) -> Result<Self, NotationError> {
+        Move::parse::<EightByEight>(text)
+    }
+
+    /// Create a new [Move] instance using the given checkers notation text, numbering squares
+    /// according to the given [Topology].
+    pub fn parse<T: Topology>(text: &str) -> Result<Self, NotationError> {
+        Move::parse_with::<T>(text, Notation::Numeric)
+    }
+
+    /// Create a new [Move] instance using checkers notation text that writes squares in the given
+    /// [Notation], such as `22-18` or `c3-d4`, on the given [Topology].
+    pub fn parse_with<T: Topology>(text: &str, notation: Notation) -> Result<Self, NotationError> {
         lazy_static! {
             static ref CN_PATTERN: Regex =
                 Regex::new(r"^([1-9]+[0-9]*)([-xX])([1-9]+[0-9]*)$").unwrap();
+            static ref ALGEBRAIC_PATTERN: Regex =
+                Regex::new(r"^([a-z][1-9][0-9]?)([-xX])([a-z][1-9][0-9]?)$").unwrap();
         }
 
-        match CN_PATTERN.captures(text) {
-            Some(captures) => Move::parse_captures(captures),
+        let pattern = match notation {
+            Notation::Numeric => &*CN_PATTERN,
+            Notation::Algebraic => &*ALGEBRAIC_PATTERN,
+        };
+        match pattern.captures(text) {
+            Some(captures) => Move::parse_captures::<T>(captures, notation),
             None => Err(NotationError::InvalidFormat),
         }
     }
 
-    fn parse_captures(captures: Captures) -> Result<Move, NotationError> {
-        let source_text = captures.get(1).unwrap().as_str();
-        let dest_text = captures.get(3).unwrap().as_str();
+    fn parse_captures<T: Topology>(
+        captures: Captures,
+        notation: Notation,
+    ) -> Result<Move, NotationError> {
+        let parse_cell = |index: usize| {
+            let text = captures.get(index).unwrap().as_str();
+            notation.parse_cell::<T>(text)
+        };
 
-        let source = Square::try_from(source_text)?;
-        let dest = Square::try_from(dest_text)?;
+        let source = parse_cell(1)?;
+        let destination = parse_cell(3)?;
+        Ok(Move::on::<T>(source, destination))
+    }
 
-        Ok(Move::from_squares(source, dest))
+    /// Writes this move in checkers notation, writing squares of the given [Topology] in the given
+    /// [Notation], or None if either cell is not a square of the topology. Source and destination
+    /// are separated by a cross for captures and by a dash otherwise.
+    pub fn format_with<T: Topology>(&self, notation: Notation) -> Option<String> {
+        let separator = if self.capture.is_some() { "x" } else { "-" };
+        let source = notation.format_cell::<T>(self.source)?;
+        let destination = notation.format_cell::<T>(self.destination)?;
+        Some(format!("{source}{separator}{destination}"))
     }
 
     /// Retrieves a copy of this moves source.
@@ -285,186 +487,337 @@ impl TryFrom<&str> for Move {
     }
 }
 
+// Kept fallible as it has been since the first release, so that existing callers keep compiling.
+#[allow(clippy::infallible_try_from)]
 impl TryFrom<(MonoBitBoard, MonoBitBoard)> for Move {
     type Error = Infallible;
 
-    /// Converts a tuple of [MonoBitBoard] into a [Move] instance.
+    /// Converts a tuple of [MonoBitBoard] of the classical [EightByEight] board into a [Move]
+    /// instance. See [Move::on] for cells of other topologies.
     fn try_from(value: (MonoBitBoard, MonoBitBoard)) -> Result<Self, Self::Error> {
         Ok(Move::new(value.0, value.1))
     }
 }
 
-impl TryFrom<(Square, Square)> for Move {
-    type Error = Infallible;
-
-    /// Converts a tuple of [Square] into a [Move] instance.
-    fn try_from(value: (Square, Square)) -> Result<Self, Self::Error> {
-        let m = Move::new(MonoBitBoard::from(value.0), MonoBitBoard::from(value.1));
-        Ok(m)
-    }
-}
-
-const RED_PIECE_MOVES: &[BitBoard; 32] = &[
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b01000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b01010000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00010100_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000101_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00010000_10100000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b01000100_00101000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00010001_00001010_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000100_00000010_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00100000_01000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_10001000_01010000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00100010_00010100_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00001000_00000101_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00010000_10100000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_01000100_00101000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00010001_00001010_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000100_00000010_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00100000_01000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_10001000_01010000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00100010_00010100_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00001000_00000101_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00010000_10100000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_01000100_00101000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00010001_00001010_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000100_00000010_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100000_01000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10001000_01010000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100010_00010100_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00001000_00000101_00000000),
-];
-
-const BLACK_PIECE_MOVES: &[BitBoard; 32] = &[
-    BitBoard::new(0b00000000_10100000_00010000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00101000_01000100_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00001010_00010001_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000010_00000100_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_01000000_00100000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_01010000_10001000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00010100_00100010_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000101_00001000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_10100000_00010000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00101000_01000100_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00001010_00010001_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000010_00000100_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_01000000_00100000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_01010000_10001000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00010100_00100010_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000101_00001000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10100000_00010000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00101000_01000100_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00001010_00010001_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000010_00000100_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_01000000_00100000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_01010000_10001000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00010100_00100010),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000101_00001000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_10100000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00101000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00001010),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000010),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
-];
-
-const KING_MOVES: &[BitBoard; 32] = &[
-    BitBoard::new(0b00000000_10100000_00010000_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00101000_01000100_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00001010_00010001_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00000010_00000100_00000000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b01000000_00000000_01000000_00100000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b01010000_00000000_01010000_10001000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00010100_00000000_00010100_00100010_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00000101_00000000_00000101_00001000_00000000_00000000_00000000_00000000),
-    BitBoard::new(0b00010000_10100000_00000000_10100000_00010000_00000000_00000000_00000000),
-    BitBoard::new(0b01000100_00101000_00000000_00101000_01000100_00000000_00000000_00000000),
-    BitBoard::new(0b00010001_00001010_00000000_00001010_00010001_00000000_00000000_00000000),
-    BitBoard::new(0b00000100_00000010_00000000_00000010_00000100_00000000_00000000_00000000),
-    BitBoard::new(0b00000000_00100000_01000000_00000000_01000000_00100000_00000000_00000000),
-    BitBoard::new(0b00000000_10001000_01010000_00000000_01010000_10001000_00000000_00000000),
-    BitBoard::new(0b00000000_00100010_00010100_00000000_00010100_00100010_00000000_00000000),
-    BitBoard::new(0b00000000_00001000_00000101_00000000_00000101_00001000_00000000_00000000),
-    BitBoard::new(0b00000000_00000000_00010000_10100000_00000000_10100000_00010000_00000000),
-    BitBoard::new(0b00000000_00000000_01000100_00101000_00000000_00101000_01000100_00000000),
-    BitBoard::new(0b00000000_00000000_00010001_00001010_00000000_00001010_00010001_00000000),
-    BitBoard::new(0b00000000_00000000_00000100_00000010_00000000_00000010_00000100_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00100000_01000000_00000000_01000000_00100000),
-    BitBoard::new(0b00000000_00000000_00000000_10001000_01010000_00000000_01010000_10001000),
-    BitBoard::new(0b00000000_00000000_00000000_00100010_00010100_00000000_00010100_00100010),
-    BitBoard::new(0b00000000_00000000_00000000_00001000_00000101_00000000_00000101_00001000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00010000_10100000_00000000_10100000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_01000100_00101000_00000000_00101000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00010001_00001010_00000000_00001010),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000100_00000010_00000000_00000010),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100000_01000000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10001000_01010000_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100010_00010100_00000000),
-    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00001000_00000101_00000000),
-];
-
-/// Capable of generating all possible moves. The key different between [MoveGenerator]
-/// and [MoveIter] is that [MoveIter] only yields valid moves in the context of the provided
-/// [BoardState]. [MoveGenerator] blindly yields all the legal moves a particular piece can have.
-struct MoveGenerator<'a> {
+/// Capable of generating every move the pieces of a player can legally make on a [BoardState]
+/// under a set of [Rules]. Moves of men and of kings that do not fly are generated for every piece
+/// at once by shifting bitboards, while flying kings are followed along their rays one at a time.
+/// The key difference between [MoveGenerator] and [MoveIter] is that [MoveGenerator] works on any
+/// selection of pieces and does not enforce mandatory captures.
+pub(crate) struct MoveGenerator<'a, R> {
     board_state: &'a BoardState,
     player: Player,
+    rules: R,
+    captured: BitBoard,
 }
 
-impl<'a> MoveGenerator<'a> {
-    /// Creates a new [MoveGenerator] instance given a [BoardState] reference and [Player].
-    pub fn new(board_state: &'a BoardState, player: Player) -> Self {
+impl<'a, R: Rules> MoveGenerator<'a, R> {
+    /// Creates a new [MoveGenerator] instance given a [BoardState] reference, [Player] and rules.
+    pub fn new(board_state: &'a BoardState, player: Player, rules: R) -> Self {
         MoveGenerator {
             board_state,
             player,
+            rules,
+            captured: BitBoard::new(0),
         }
     }
 
-    /// Provides an iterator of moves given a specific board cell.
-    pub fn by_cell(&self, cell: MonoBitBoard) -> impl Iterator<Item = Move> {
-        let inf_cell_iter = [cell].into_iter().cycle();
-        let moves_by_cell = self.moves_by_cell(cell);
-        inf_cell_iter
-            .zip(moves_by_cell)
-            .map(|m| Move::try_from(m).unwrap())
+    /// Sets the pieces that have already been captured during the turn in progress. Captured
+    /// pieces stay on the board until the turn is completed and can not be captured again.
+    pub fn with_captured(mut self, captured: BitBoard) -> Self {
+        self.captured = captured;
+        self
     }
 
-    fn moves_by_cell(&self, cell: MonoBitBoard) -> CellIter {
-        self.get_move_bitboard(self.board_state, self.player, cell)
-            .unwrap_or(&BitBoard::new(0))
-            .used_cells()
+    /// Provides every move the given cell is able to make, captures first.
+    pub fn moves(&self, cell: MonoBitBoard) -> Vec<Move> {
+        self.all_moves(BitBoard::from(cell))
     }
 
-    fn get_move_bitboard(
-        &self,
-        board_state: &BoardState,
-        player: Player,
-        cell: MonoBitBoard,
-    ) -> Option<&BitBoard> {
-        let Ok(square) = Square::try_from(cell) else {
-            return None;
-        };
+    /// Provides every capturing move the given cell is able to make.
+    pub fn captures(&self, cell: MonoBitBoard) -> Vec<Move> {
+        self.all_captures(BitBoard::from(cell))
+    }
+
+    /// Provides every move the given pieces are able to make. Moves are grouped by piece, starting
+    /// from the piece on the least significant bit, with the captures of a piece coming first.
+    pub fn all_moves(&self, pieces: BitBoard) -> Vec<Move> {
+        let mut moves = self.all_captures(pieces);
+        moves.extend(self.all_quiet_moves(pieces));
+        sort_by_source(&mut moves);
+        moves
+    }
 
-        let move_index = (square.to_number() - 1) as usize;
-        let is_king = board_state.is_king(cell);
-        let move_bitboard = match is_king {
-            true => KING_MOVES.get(move_index),
-            false => match player {
-                Player::Red => RED_PIECE_MOVES.get(move_index),
-                Player::Black => BLACK_PIECE_MOVES.get(move_index),
-            },
+    /// Provides every move the given pieces are able to make while honouring mandatory captures,
+    /// only captures being provided whenever one is available and the rules make them mandatory.
+    /// Moves are grouped by piece like [MoveGenerator::all_moves].
+    pub fn legal_moves(&self, pieces: BitBoard) -> Vec<Move> {
+        let mut moves = self.all_captures(pieces);
+        if self.rules.mandatory_capture() && !moves.is_empty() {
+            return moves;
+        }
+        if moves.is_empty() {
+            return self.all_quiet_moves(pieces);
+        }
+        moves.extend(self.all_quiet_moves(pieces));
+        sort_by_source(&mut moves);
+        moves
+    }
+
+    /// Provides every non-capturing move the given pieces are able to make, grouped by piece.
+    pub fn all_quiet_moves(&self, pieces: BitBoard) -> Vec<Move> {
+        let (men, kings, flying_kings) = self.split(pieces);
+        // Men and kings that do not fly make at most a single move in every direction.
+        let capacity = men.count_ones() as usize
+            * R::Topology::forward_directions(self.player).len()
+            + kings.count_ones() as usize * R::Topology::directions().len();
+        let mut moves = Vec::with_capacity(capacity);
+        for direction in R::Topology::forward_directions(self.player) {
+            self.push_steps(men, *direction, &mut moves);
+        }
+        for direction in R::Topology::directions() {
+            self.push_steps(kings, *direction, &mut moves);
+        }
+        for king in flying_kings.used_cells() {
+            moves.extend(self.flying_quiet_moves(king));
+        }
+        sort_by_source(&mut moves);
+        moves
+    }
+
+    /// Provides every capturing move the given pieces are able to make, grouped by piece.
+    pub fn all_captures(&self, pieces: BitBoard) -> Vec<Move> {
+        let (men, kings, flying_kings) = self.split(pieces);
+        let (man_targets, king_targets) = self.targets();
+        let mut moves = vec![];
+        for direction in self.man_capture_directions() {
+            self.push_jumps(men, man_targets, *direction, &mut moves);
+        }
+        for direction in R::Topology::capture_directions() {
+            self.push_jumps(kings, king_targets, *direction, &mut moves);
+        }
+        for king in flying_kings.used_cells() {
+            moves.extend(self.flying_captures(king, king_targets));
+        }
+        sort_by_source(&mut moves);
+        moves
+    }
+
+    /// Calculates which of the given pieces are able to make a capture, without generating the
+    /// captures of pieces that do not fly.
+    pub fn capturing_pieces(&self, pieces: BitBoard) -> BitBoard {
+        let (men, kings, flying_kings) = self.split(pieces);
+        let (man_targets, king_targets) = self.targets();
+        let mut capturing = BitBoard::default();
+        for direction in self.man_capture_directions() {
+            capturing |= self.jumpers(men, man_targets, *direction);
+        }
+        for direction in R::Topology::capture_directions() {
+            capturing |= self.jumpers(kings, king_targets, *direction);
+        }
+        for king in flying_kings.used_cells() {
+            if !self.flying_captures(king, king_targets).is_empty() {
+                capturing |= king;
+            }
+        }
+        capturing
+    }
+
+    /// Calculates every cell the given cell would be able to reach without and with capturing
+    /// if the rest of the board was empty.
+    pub fn reach(&self, cell: MonoBitBoard) -> (BitBoard, BitBoard) {
+        let ray = |direction: &Direction, skip: usize| {
+            let mut cells = BitBoard::new(0);
+            let mut current = cell;
+            let mut distance = 0;
+            while let Some(next) = R::Topology::step(current, *direction) {
+                distance += 1;
+                if distance > skip {
+                    cells |= next;
+                    if !self.is_flying(cell) {
+                        break;
+                    }
+                }
+                current = next;
+            }
+            cells
         };
 
-        move_bitboard
+        let quiet = self
+            .move_directions(cell)
+            .iter()
+            .fold(BitBoard::new(0), |cells, direction| {
+                cells | ray(direction, 0)
+            });
+        let captures = self
+            .capture_directions(cell)
+            .iter()
+            .fold(BitBoard::new(0), |cells, direction| {
+                cells | ray(direction, 1)
+            });
+        (quiet, captures)
+    }
+
+    // Splits the given pieces into men, kings moving a single square at a time and flying kings.
+    fn split(&self, pieces: BitBoard) -> (BitBoard, BitBoard, BitBoard) {
+        let kings = pieces & self.board_state.all_kings();
+        match self.rules.flying_kings() {
+            true => (pieces ^ kings, BitBoard::default(), kings),
+            false => (pieces ^ kings, kings, BitBoard::default()),
+        }
+    }
+
+    // Retrieves the pieces men and kings are able to capture. Pieces captured earlier in the turn
+    // can not be captured again.
+    fn targets(&self) -> (BitBoard, BitBoard) {
+        let targets = self.board_state.pieces_by_player(self.opponent()) & !self.captured;
+        match self.rules.men_capture_kings() {
+            true => (targets, targets),
+            false => (targets & !self.board_state.all_kings(), targets),
+        }
+    }
+
+    fn empty_cells(&self) -> BitBoard {
+        !self.board_state.all_pieces()
+    }
+
+    // Pushes the non-capturing moves of the given pieces stepping once in the given direction.
+    fn push_steps(&self, pieces: BitBoard, direction: Direction, moves: &mut Vec<Move>) {
+        let destinations = R::Topology::shift(pieces, direction) & self.empty_cells();
+        moves.reserve(destinations.count_ones() as usize);
+        for destination in destinations.used_cells() {
+            let Some(source) = R::Topology::step(destination, direction.opposite()) else {
+                continue;
+            };
+            moves.push(Move {
+                source,
+                destination,
+                capture: None,
+            });
+        }
+    }
+
+    // Pushes the captures of the given pieces jumping a target in the given direction.
+    fn push_jumps(
+        &self,
+        pieces: BitBoard,
+        targets: BitBoard,
+        direction: Direction,
+        moves: &mut Vec<Move>,
+    ) {
+        let opposite = direction.opposite();
+        let landings = self.landings(pieces, targets, direction);
+        moves.reserve(landings.count_ones() as usize);
+        for destination in landings.used_cells() {
+            let Some(capture) = R::Topology::step(destination, opposite) else {
+                continue;
+            };
+            let Some(source) = R::Topology::step(capture, opposite) else {
+                continue;
+            };
+            moves.push(Move::with_capture(source, destination, capture));
+        }
+    }
+
+    // Calculates which of the given pieces are able to jump a target in the given direction.
+    fn jumpers(&self, pieces: BitBoard, targets: BitBoard, direction: Direction) -> BitBoard {
+        let opposite = direction.opposite();
+        let landings = self.landings(pieces, targets, direction);
+        R::Topology::shift(R::Topology::shift(landings, opposite), opposite)
+    }
+
+    // Calculates where the given pieces land when jumping a target in the given direction.
+    fn landings(&self, pieces: BitBoard, targets: BitBoard, direction: Direction) -> BitBoard {
+        let jumped = R::Topology::shift(pieces, direction) & targets;
+        R::Topology::shift(jumped, direction) & self.empty_cells()
+    }
+
+    fn flying_quiet_moves(&self, king: MonoBitBoard) -> Vec<Move> {
+        let mut moves = vec![];
+        for direction in R::Topology::directions() {
+            let mut current = king;
+            while let Some(next) = R::Topology::step(current, *direction) {
+                if self.board_state.is_piece(next) {
+                    break;
+                }
+                // Constructed directly as a long diagonal move could be mistaken for a capture.
+                moves.push(Move {
+                    source: king,
+                    destination: next,
+                    capture: None,
+                });
+                current = next;
+            }
+        }
+        moves
+    }
+
+    fn flying_captures(&self, king: MonoBitBoard, targets: BitBoard) -> Vec<Move> {
+        let mut moves = vec![];
+        for direction in R::Topology::capture_directions() {
+            let mut target = king;
+            let target = loop {
+                match R::Topology::step(target, *direction) {
+                    Some(next) if self.board_state.is_piece(next) => break Some(next),
+                    Some(next) => target = next,
+                    None => break None,
+                }
+            };
+            let Some(target) = target.filter(|target| targets.contains(*target)) else {
+                continue;
+            };
+
+            let mut current = target;
+            while let Some(landing) = R::Topology::step(current, *direction) {
+                if self.board_state.is_piece(landing) {
+                    break;
+                }
+                moves.push(Move::with_capture(king, landing, target));
+                current = landing;
+            }
+        }
+        moves
+    }
+
+    fn opponent(&self) -> Player {
+        match self.player {
+            Player::Red => Player::Black,
+            Player::Black => Player::Red,
+        }
+    }
+
+    fn is_flying(&self, cell: MonoBitBoard) -> bool {
+        self.rules.flying_kings() && self.board_state.is_king(cell)
+    }
+
+    fn man_capture_directions(&self) -> &'static [Direction] {
+        match self.rules.men_capture_backward() {
+            true => R::Topology::capture_directions(),
+            false => R::Topology::forward_directions(self.player),
+        }
+    }
+
+    fn move_directions(&self, cell: MonoBitBoard) -> &'static [Direction] {
+        match self.board_state.is_king(cell) {
+            true => R::Topology::directions(),
+            false => R::Topology::forward_directions(self.player),
+        }
+    }
+
+    fn capture_directions(&self, cell: MonoBitBoard) -> &'static [Direction] {
+        match self.board_state.is_king(cell) {
+            true => R::Topology::capture_directions(),
+            false => self.man_capture_directions(),
+        }
     }
 }
 
+// Groups moves by the piece making them, starting from the piece on the least significant bit.
+// Sorting is stable so that the moves of a piece keep the order they were generated in.
+fn sort_by_source(moves: &mut [Move]) {
+    moves.sort_by_key(|m| m.source.value().trailing_zeros());
+}
+
 /// Error that can occur while performing a move action.
 #[derive(Debug, Error, PartialEq)]
 pub enum MoveError {
@@ -490,17 +843,91 @@ pub enum MoveError {
 
     #[error("A capture move must jump a square containing an enemy piece.")]
     InvalidCapture,
+
+    #[error("A capture is available and must be taken by one of the following pieces: {}", format_squares(.pieces))]
+    CaptureRequired { pieces: Vec<u8> },
+
+    #[error("A turn must contain at least a single move.")]
+    EmptyTurn,
+
+    #[error("Every move of a turn must be made by the same piece, continuing from the square the previous move ended on.")]
+    MixedPieces,
+
+    #[error("A non-capturing move must be the only move of a turn.")]
+    QuietMoveNotAlone,
+
+    #[error("The piece on square {piece} is able to continue capturing and must complete the capture sequence.")]
+    IncompleteCapture { piece: u8 },
+
+    #[error("The moving piece was crowned, which ends the turn. No further moves can be made.")]
+    TurnEndedByPromotion,
+
+    #[error(
+        "A capture sequence can not continue in the direction opposite of the previous capture."
+    )]
+    CaptureReversed,
+
+    #[error("A turn capturing {captures} pieces is available and must be taken instead.")]
+    MajorityCaptureRequired { captures: usize },
+
+    #[error(
+        "A capture can be made with a king and must be taken instead of capturing with a man."
+    )]
+    KingCaptureRequired,
+
+    #[error("A turn capturing {kings} kings is available and must be taken instead.")]
+    MostKingsCaptureRequired { kings: usize },
+
+    #[error("A turn capturing a king earlier in the capture sequence is available and must be taken instead.")]
+    EarliestKingCaptureRequired,
+
+    #[error("A turn capturing pieces worth {value} is available and must be taken instead.")]
+    HighestValueCaptureRequired { value: usize },
+
+    #[error("The king on square {piece} has moved {moves} times in a row and may not move again until a man is moved or a capture is made.")]
+    KingMoveLimitReached { piece: u8, moves: usize },
+}
+
+fn format_squares(squares: &[u8]) -> String {
+    squares
+        .iter()
+        .map(|square| square.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
 /// Capable of validating that a given move is valid provided additional [BoardState] context.
-pub struct MoveValidator<'a> {
+pub struct MoveValidator<'a, R = EnglishRules> {
     board_state: &'a BoardState,
+    rules: R,
+    captured: BitBoard,
+    capturing_pieces: OnceCell<BitBoard>,
 }
 
 impl<'a> MoveValidator<'a> {
-    /// Creates a new [MoveValidator] instance from the given [BoardState].
+    /// Creates a new [MoveValidator] instance from the given [BoardState] that validates moves
+    /// according to [EnglishRules].
     pub fn new(board_state: &'a BoardState) -> Self {
-        MoveValidator { board_state }
+        MoveValidator::with_rules(board_state, EnglishRules)
+    }
+}
+
+impl<'a, R: Rules> MoveValidator<'a, R> {
+    /// Creates a new [MoveValidator] instance from the given [BoardState] that validates moves
+    /// according to the given rules.
+    pub fn with_rules(board_state: &'a BoardState, rules: R) -> Self {
+        MoveValidator {
+            board_state,
+            rules,
+            captured: BitBoard::new(0),
+            capturing_pieces: OnceCell::new(),
+        }
+    }
+
+    /// Sets the pieces that have already been captured during the turn in progress.
+    pub(crate) fn with_captured(mut self, captured: BitBoard) -> Self {
+        self.captured = captured;
+        self
     }
 
     /// Validates a given move is valid per this validator's board state.
@@ -510,10 +937,40 @@ impl<'a> MoveValidator<'a> {
     {
         let m = m.try_into().map_err(|_| MoveError::InvalidConstruction)?;
 
-        self.valid_piece_selection(&m)?;
-        self.valid_destination(&m)?;
+        let m = self.resolve(&m)?;
+        self.valid_capture_obligation(&m)
+    }
 
-        Ok(())
+    /// Validates the piece selection and destination of the given move and returns the move as
+    /// it will be applied to the board, including the piece it captures. Mandatory captures are
+    /// not enforced.
+    pub(crate) fn resolve(&self, m: &Move) -> Result<Move, MoveError> {
+        self.valid_piece_selection(m)?;
+        self.valid_destination(m)
+    }
+
+    /// Retrieves a bitboard representing every piece of the current player that is able to make
+    /// a capture. The value is calculated once and reused by every following validation.
+    pub fn capturing_pieces(&self) -> BitBoard {
+        *self.capturing_pieces.get_or_init(|| {
+            self.generator()
+                .capturing_pieces(self.board_state.current_player_pieces())
+        })
+    }
+
+    /// Returns true if the current player has at least one capture available and is therefore
+    /// obligated to make a capturing move. Otherwise, returns false.
+    pub fn is_capture_required(&self) -> bool {
+        self.rules.mandatory_capture() && !self.capturing_pieces().empty()
+    }
+
+    fn generator(&self) -> MoveGenerator<'a, R> {
+        MoveGenerator::new(
+            self.board_state,
+            self.board_state.current_player,
+            self.rules,
+        )
+        .with_captured(self.captured)
     }
 
     fn valid_piece_selection(&self, m: &Move) -> Result<(), MoveError> {
@@ -526,56 +983,74 @@ impl<'a> MoveValidator<'a> {
         Ok(())
     }
 
-    fn valid_destination(&self, m: &Move) -> Result<(), MoveError> {
-        let generator = MoveGenerator::new(self.board_state, self.board_state.current_player);
-        let mut destinations = generator.by_cell(m.source).map(|m| m.destination);
+    fn valid_destination(&self, m: &Move) -> Result<Move, MoveError> {
+        let generator = self.generator();
+        let legal_move = generator
+            .moves(m.source)
+            .into_iter()
+            .find(|legal_move| legal_move.destination == m.destination);
+        if let Some(legal_move) = legal_move {
+            return Ok(legal_move);
+        }
 
-        if destinations.all(|dest| dest != m.destination) {
+        let (quiet_reach, capture_reach) = generator.reach(m.source);
+        if !(quiet_reach | capture_reach).contains(m.destination) {
             return Err(MoveError::IllegalDestination);
         }
-        if self.board_state.all_pieces() & m.destination != 0 {
+        if self.board_state.is_piece(m.destination) {
             return Err(MoveError::DestinationOccupied);
         }
-        if let Some(piece) = m.capture() {
-            if !self.board_state.is_other_player_piece(piece) {
-                return Err(MoveError::InvalidCapture);
-            }
+        if !capture_reach.contains(m.destination) {
+            return Err(MoveError::IllegalDestination);
         }
+        Err(MoveError::InvalidCapture)
+    }
 
+    pub(crate) fn valid_capture_obligation(&self, m: &Move) -> Result<(), MoveError> {
+        if m.capture().is_none() && self.is_capture_required() {
+            let mut pieces = self
+                .capturing_pieces()
+                .used_cells()
+                .filter_map(R::Topology::number)
+                .collect::<Vec<u8>>();
+            pieces.sort();
+            return Err(MoveError::CaptureRequired { pieces });
+        }
         Ok(())
     }
 }
 
 /// Iterator capable of generating all possible moves for a given [BoardState]
-/// and [Player] of that board.
-pub struct MoveIter<'a> {
-    player_pieces: CellIter,
-    generator: MoveGenerator<'a>,
-    validator: MoveValidator<'a>,
+/// and [Player] of that board. When captures are mandatory, only capturing moves are yielded
+/// whenever at least one capture is available. Moves are grouped by piece. Moves of all pieces are
+/// generated at once when the iterator is created, so it does not borrow the board state.
+pub struct MoveIter {
+    moves: std::vec::IntoIter<Move>,
 }
 
-impl<'a> MoveIter<'a> {
-    /// Creates a new [MoveIter] instance from given board reference and player.
-    pub fn new(board_state: &'a BoardState, player: Player) -> Self {
-        let player_pieces = board_state.pieces_by_player(player).used_cells();
-        let generator = MoveGenerator::new(board_state, player);
-        let validator = MoveValidator::new(board_state);
+impl MoveIter {
+    /// Creates a new [MoveIter] instance from given board reference and player that generates
+    /// moves according to [EnglishRules].
+    pub fn new(board_state: &BoardState, player: Player) -> Self {
+        MoveIter::with_rules(board_state, player, EnglishRules)
+    }
+
+    /// Creates a new [MoveIter] instance from given board reference and player that generates
+    /// moves according to the given rules.
+    pub fn with_rules<R: Rules>(board_state: &BoardState, player: Player, rules: R) -> Self {
+        let pieces = board_state.pieces_by_player(player);
+        let moves = MoveGenerator::new(board_state, player, rules).legal_moves(pieces);
 
         MoveIter {
-            player_pieces,
-            generator,
-            validator,
+            moves: moves.into_iter(),
         }
     }
 }
 
-impl<'a> Iterator for MoveIter<'a> {
+impl Iterator for MoveIter {
     type Item = Move;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.player_pieces
-            .by_ref()
-            .flat_map(|piece| self.generator.by_cell(piece))
-            .find(|m| self.validator.validate(m.clone()).is_ok())
+        self.moves.next()
     }
 }
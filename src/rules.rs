@@ -0,0 +1,507 @@
+use std::fmt::Debug;
+
+use crate::board::Player;
+use crate::topology::{EightByEight, MirroredEightByEight, MirroredFromRedEightByEight, Topology};
+
+/// Describes what happens when a man reaches the king row in the middle of a capture sequence.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PromotionRule {
+    /// The man is crowned and the turn ends immediately, even if the new king would be able to
+    /// continue capturing. This is the behaviour of American/English checkers.
+    #[default]
+    EndsTurn,
+
+    /// The man is crowned and must continue the capture sequence as a king if able to.
+    ContinuesAsKing,
+
+    /// The man continues the capture sequence as a man if able to and is only crowned when the
+    /// turn ends on the king row. Passing through the king row does not crown the man.
+    ContinuesAsMan,
+}
+
+/// Describes which player wins the game once the player to move is left without any moves.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum WinCondition {
+    /// The player left without any moves, including by losing every piece, loses the game.
+    #[default]
+    Standard,
+
+    /// The player left without any moves, including by losing every piece, wins the game. Used
+    /// by giveaway variants.
+    Inverted,
+}
+
+/// Describes a criterion used to decide which capturing turns may be taken when several are
+/// available. Variants list their criteria in order of precedence, each criterion only deciding
+/// between the turns that are equal according to every criterion before it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum CapturePriority {
+    /// The turn capturing the most pieces must be taken.
+    MostCaptures,
+
+    /// A capture must be made with a king rather than a man.
+    CapturingKing,
+
+    /// The turn capturing the most kings must be taken.
+    MostKingsCaptured,
+
+    /// The turn capturing a king the earliest in its capture sequence must be taken.
+    EarliestKingCaptured,
+
+    /// The turn capturing the pieces with the highest total value must be taken, every captured
+    /// man and king being worth the given value.
+    HighestValue { man: usize, king: usize },
+}
+
+/// Describes the rules of a checkers variant. Boards, validators and move iterators are generic
+/// over a [Rules] implementation so that the same machinery can be used to play every variant.
+pub trait Rules: Copy + Debug {
+    /// The board the variant is played on.
+    type Topology: Topology;
+
+    /// The player that takes the first turn of a game.
+    fn first_player(&self) -> Player;
+
+    /// Returns true if men are allowed to capture backwards. Men are only ever allowed to make
+    /// non-capturing moves forward.
+    fn men_capture_backward(&self) -> bool;
+
+    /// Returns true if kings are able to move and capture across any distance along a diagonal,
+    /// rather than being limited to neighbouring squares.
+    fn flying_kings(&self) -> bool;
+
+    /// Returns true if a capture must be made whenever one is available. Regardless of this rule,
+    /// a capture sequence that has been started must always be completed.
+    fn mandatory_capture(&self) -> bool;
+
+    /// Returns true if the turn capturing the most pieces must be chosen when several captures
+    /// are available.
+    fn majority_capture(&self) -> bool;
+
+    /// Describes what happens when a man is crowned during a capture sequence.
+    fn promotion_rule(&self) -> PromotionRule;
+
+    /// Returns true if men are allowed to capture kings. Kings are always able to capture kings.
+    /// Defaults to true.
+    fn men_capture_kings(&self) -> bool {
+        true
+    }
+
+    /// Returns true if captured pieces are removed from the board one at a time as they are
+    /// jumped, rather than once the turn is completed. Defaults to false.
+    fn immediate_capture_removal(&self) -> bool {
+        false
+    }
+
+    /// Returns true if a capture sequence may continue in the direction opposite of the previous
+    /// capture. Defaults to true.
+    fn capture_reversal(&self) -> bool {
+        true
+    }
+
+    /// Describes which player wins once the player to move has no moves left. Defaults to
+    /// [WinCondition::Standard].
+    fn win_condition(&self) -> WinCondition {
+        WinCondition::Standard
+    }
+
+    /// Limits the amount of consecutive non-capturing moves the same king may make while its
+    /// player still has men on the board, or None if kings may move freely. Defaults to None.
+    fn king_move_limit(&self) -> Option<usize> {
+        None
+    }
+
+    /// Number identifying the variant in the GameType tag of Portable Draughts Notation, or None
+    /// if the variant has no such number. Defaults to None.
+    fn game_type(&self) -> Option<u8> {
+        None
+    }
+
+    /// Lists the criteria deciding which capturing turns may be taken, in order of precedence.
+    /// Defaults to requiring the most captures when [Rules::majority_capture] is enabled and
+    /// allowing any capture otherwise.
+    fn capture_priorities(&self) -> &'static [CapturePriority] {
+        match self.majority_capture() {
+            true => &[CapturePriority::MostCaptures],
+            false => &[],
+        }
+    }
+}
+
+/// Rules of American/English checkers, also known as English draughts. Black moves first, men
+/// only capture forward, kings move a single square at a time, captures are mandatory but any
+/// capture may be chosen and crowning a man ends the turn.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EnglishRules;
+
+impl Rules for EnglishRules {
+    type Topology = EightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Black
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        false
+    }
+
+    fn flying_kings(&self) -> bool {
+        false
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        false
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::EndsTurn
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(21)
+    }
+}
+
+/// Rules of Russian draughts, also known as shashki. Played on the classical board with red taking
+/// the role of white and moving first. Men capture backwards, kings fly, captures are mandatory
+/// but any capture may be chosen and a man crowned during a capture continues as a king.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RussianRules;
+
+impl Rules for RussianRules {
+    type Topology = EightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        true
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        false
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::ContinuesAsKing
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(25)
+    }
+}
+
+/// Rules of Italian draughts, played on the [MirroredEightByEight] board with red taking the role
+/// of white and moving first. Men only capture forward and can not capture kings, kings move a
+/// single square at a time and crowning a man ends the turn. When several captures are available
+/// the longest must be taken, then a capture made by a king, then the capture taking the most
+/// kings and finally the capture taking a king the earliest.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ItalianRules;
+
+impl Rules for ItalianRules {
+    type Topology = MirroredEightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        false
+    }
+
+    fn flying_kings(&self) -> bool {
+        false
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        true
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::EndsTurn
+    }
+
+    fn men_capture_kings(&self) -> bool {
+        false
+    }
+
+    fn capture_priorities(&self) -> &'static [CapturePriority] {
+        &[
+            CapturePriority::MostCaptures,
+            CapturePriority::CapturingKing,
+            CapturePriority::MostKingsCaptured,
+            CapturePriority::EarliestKingCaptured,
+        ]
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(22)
+    }
+}
+
+/// Rules of Brazilian draughts, which are the rules of international draughts played on the
+/// classical board. Red takes the role of white and moves first, men capture backwards, kings fly,
+/// the turn capturing the most pieces must be taken and men are only crowned when ending their
+/// turn on the king row.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct BrazilianRules;
+
+impl Rules for BrazilianRules {
+    type Topology = EightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        true
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        true
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::ContinuesAsMan
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(26)
+    }
+}
+
+/// Rules of American pool checkers. Black moves first, men capture backwards, kings fly and
+/// captures are mandatory but any capture may be chosen. A man reaching the king row during a
+/// capture continues capturing as a man and is only crowned when ending its turn there.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct PoolRules;
+
+impl Rules for PoolRules {
+    type Topology = EightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Black
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        true
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        false
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::ContinuesAsMan
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(23)
+    }
+}
+
+/// Rules of Spanish draughts, played on the [MirroredFromRedEightByEight] board with red taking
+/// the role of white and moving first. Men only capture forward, kings fly and crowning a man ends
+/// the turn. When several captures are available the turn capturing the most pieces must be
+/// taken, followed by the turn capturing the most kings.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SpanishRules;
+
+/// Rules of Portuguese draughts, which are played the same way as Spanish draughts.
+pub type PortugueseRules = SpanishRules;
+
+impl Rules for SpanishRules {
+    type Topology = MirroredFromRedEightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        false
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        true
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::EndsTurn
+    }
+
+    fn capture_priorities(&self) -> &'static [CapturePriority] {
+        &[
+            CapturePriority::MostCaptures,
+            CapturePriority::MostKingsCaptured,
+        ]
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(24)
+    }
+}
+
+/// Rules of giveaway checkers, also known as suicide checkers or antidraughts. Moves are made
+/// according to [EnglishRules], including mandatory captures, but the first player to lose every
+/// piece or to be left without any moves wins the game.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GiveawayRules;
+
+impl Rules for GiveawayRules {
+    type Topology = EightByEight;
+
+    fn first_player(&self) -> Player {
+        EnglishRules.first_player()
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        EnglishRules.men_capture_backward()
+    }
+
+    fn flying_kings(&self) -> bool {
+        EnglishRules.flying_kings()
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        EnglishRules.mandatory_capture()
+    }
+
+    fn majority_capture(&self) -> bool {
+        EnglishRules.majority_capture()
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        EnglishRules.promotion_rule()
+    }
+
+    fn win_condition(&self) -> WinCondition {
+        WinCondition::Inverted
+    }
+}
+
+/// Rule set configured through its fields, useful for experimenting with house rules or
+/// variants that are not provided out of the box. Defaults to the rules of [EnglishRules] and is
+/// always played on the classical [EightByEight] board.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CustomRules {
+    pub first_player: Player,
+    pub men_capture_backward: bool,
+    pub flying_kings: bool,
+    pub mandatory_capture: bool,
+    pub majority_capture: bool,
+    pub promotion_rule: PromotionRule,
+    pub men_capture_kings: bool,
+    pub immediate_capture_removal: bool,
+    pub capture_reversal: bool,
+    pub win_condition: WinCondition,
+    pub king_move_limit: Option<usize>,
+}
+
+impl Default for CustomRules {
+    fn default() -> Self {
+        let rules = EnglishRules;
+        CustomRules {
+            first_player: rules.first_player(),
+            men_capture_backward: rules.men_capture_backward(),
+            flying_kings: rules.flying_kings(),
+            mandatory_capture: rules.mandatory_capture(),
+            majority_capture: rules.majority_capture(),
+            promotion_rule: rules.promotion_rule(),
+            men_capture_kings: rules.men_capture_kings(),
+            immediate_capture_removal: rules.immediate_capture_removal(),
+            capture_reversal: rules.capture_reversal(),
+            win_condition: rules.win_condition(),
+            king_move_limit: rules.king_move_limit(),
+        }
+    }
+}
+
+impl Rules for CustomRules {
+    type Topology = EightByEight;
+
+    fn first_player(&self) -> Player {
+        self.first_player
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        self.men_capture_backward
+    }
+
+    fn flying_kings(&self) -> bool {
+        self.flying_kings
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        self.mandatory_capture
+    }
+
+    fn majority_capture(&self) -> bool {
+        self.majority_capture
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        self.promotion_rule
+    }
+
+    fn men_capture_kings(&self) -> bool {
+        self.men_capture_kings
+    }
+
+    fn immediate_capture_removal(&self) -> bool {
+        self.immediate_capture_removal
+    }
+
+    fn capture_reversal(&self) -> bool {
+        self.capture_reversal
+    }
+
+    fn win_condition(&self) -> WinCondition {
+        self.win_condition
+    }
+
+    fn king_move_limit(&self) -> Option<usize> {
+        self.king_move_limit
+    }
+}
@@ -0,0 +1,397 @@
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+use crate::bitboard::{
+    BitBoard, MonoBitBoard, BOTTOM_SQUARES, LEFT_SQUARES, RIGHT_SQUARES, TOP_SQUARES,
+};
+use crate::board::{Player, INITIAL_BLACK_PIECES, INITIAL_RED_PIECES, KING_SQUARES};
+use crate::position::{Orientation, Square};
+
+/// Represents the directions a piece is able to travel in. North is the side of the board black
+/// starts on, red men therefore moving north and black men moving south.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Direction {
+    NorthWest,
+    NorthEast,
+    SouthWest,
+    SouthEast,
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every diagonal direction a piece may travel in.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthWest,
+        Direction::NorthEast,
+        Direction::SouthWest,
+        Direction::SouthEast,
+    ];
+
+    /// Every orthogonal direction a piece may travel in.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Every direction a piece may travel in, diagonals first.
+    pub const ALL: [Direction; 8] = [
+        Direction::NorthWest,
+        Direction::NorthEast,
+        Direction::SouthWest,
+        Direction::SouthEast,
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Retrieves the direction pointing the opposite way of this direction.
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::NorthWest => Direction::SouthEast,
+            Direction::NorthEast => Direction::SouthWest,
+            Direction::SouthWest => Direction::NorthEast,
+            Direction::SouthEast => Direction::NorthWest,
+            Direction::North => Direction::South,
+            Direction::East => Direction::West,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+        }
+    }
+}
+
+/// Describes the geometry of a board: which cells of a bitboard are playable squares, how those
+/// squares are numbered, how they neighbour each other and where pieces start and are crowned.
+/// Every [crate::rules::Rules] implementation is played on a single topology.
+pub trait Topology: Copy + Debug + PartialEq {
+    /// Type naming every playable square of the board.
+    type Square: Copy + Debug + Into<u8>;
+
+    /// Number of rows and columns of the board. Defaults to the 8 of the classical board.
+    const SIZE: u8 = 8;
+
+    /// Calculates the neighbouring cell of the given cell in the given direction, or None if the
+    /// cell sits on the edge of the board.
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard>;
+
+    /// Moves every given cell a single step in the given direction at once, dropping the cells
+    /// that sit on the edge of the board. Defaults to stepping every cell one at a time, which
+    /// boards backed by a regular bit layout replace with shifts and masks.
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        cells
+            .used_cells()
+            .filter_map(|cell| Self::step(cell, direction))
+            .fold(BitBoard::default(), |neighbours, neighbour| {
+                neighbours | neighbour
+            })
+    }
+
+    /// Converts a square number to the cell it represents, or None if the number does not belong
+    /// to a square of the board.
+    fn cell(number: u8) -> Option<MonoBitBoard>;
+
+    /// Converts a cell to its square number, or None if the cell is not a playable square.
+    fn number(cell: MonoBitBoard) -> Option<u8>;
+
+    /// Calculates the row and column of the given cell, both counted from the top left corner of
+    /// black's side of the board, or None if the cell is not a playable square. Defaults to the
+    /// layout of the classical board, where every cell has a bit and the top left corner is the
+    /// highest of [Topology::SIZE] squared bits.
+    fn coordinates(cell: MonoBitBoard) -> Option<(u8, u8)> {
+        Self::number(cell)?;
+        let size = u32::from(Self::SIZE);
+        let position = (size * size).checked_sub(cell.value().trailing_zeros() + 1)?;
+        Some(((position / size) as u8, (position % size) as u8))
+    }
+
+    /// Converts a row and column, both counted from the top left corner of black's side of the
+    /// board, to the cell sitting there, or None if that cell is not a playable square. Defaults
+    /// to the layout described by [Topology::coordinates].
+    fn cell_at(row: u8, column: u8) -> Option<MonoBitBoard> {
+        if row >= Self::SIZE || column >= Self::SIZE {
+            return None;
+        }
+        let size = u32::from(Self::SIZE);
+        let position = size * size - 1 - (size * u32::from(row) + u32::from(column));
+        let cell = MonoBitBoard::new(1u128.checked_shl(position)?).ok()?;
+        Self::number(cell).map(|_| cell)
+    }
+
+    /// Retrieves the pieces the given player starts the game with.
+    fn initial_pieces(player: Player) -> BitBoard;
+
+    /// Retrieves the squares where men of the given player are crowned.
+    fn promotion_squares(player: Player) -> BitBoard;
+
+    /// Retrieves every direction pieces are able to travel in. Defaults to the diagonals.
+    fn directions() -> &'static [Direction] {
+        &Direction::DIAGONAL
+    }
+
+    /// Retrieves the directions men of the given player move in. Defaults to the diagonals
+    /// pointing away from the player's side of the board.
+    fn forward_directions(player: Player) -> &'static [Direction] {
+        match player {
+            Player::Red => &[Direction::NorthWest, Direction::NorthEast],
+            Player::Black => &[Direction::SouthWest, Direction::SouthEast],
+        }
+    }
+
+    /// Retrieves every direction pieces are able to capture in. Defaults to [Topology::directions].
+    fn capture_directions() -> &'static [Direction] {
+        Self::directions()
+    }
+
+    /// Calculates the piece jumped when moving between the given cells, or None if the cells are
+    /// not exactly two squares apart along a single capture direction.
+    fn capture_between(source: MonoBitBoard, destination: MonoBitBoard) -> Option<MonoBitBoard> {
+        Self::capture_directions().iter().find_map(|direction| {
+            let direction = *direction;
+            let capture = Self::step(source, direction)?;
+            (Self::step(capture, direction)? == destination).then_some(capture)
+        })
+    }
+}
+
+/// The classical 8x8 board with 32 playable squares, numbered from the top left corner of
+/// black's side of the board. Cells use the full 64 bits of a bitboard.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct EightByEight;
+
+impl Topology for EightByEight {
+    type Square = Square;
+
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        MonoBitBoard::try_from(Self::shift(BitBoard::from(cell), direction)).ok()
+    }
+
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        match direction {
+            Direction::NorthWest => (cells & !(LEFT_SQUARES | TOP_SQUARES)) << 9,
+            Direction::NorthEast => (cells & !(RIGHT_SQUARES | TOP_SQUARES)) << 7,
+            Direction::SouthWest => (cells & !(LEFT_SQUARES | BOTTOM_SQUARES)) >> 7,
+            Direction::SouthEast => (cells & !(RIGHT_SQUARES | BOTTOM_SQUARES)) >> 9,
+            Direction::North | Direction::East | Direction::South | Direction::West => {
+                BitBoard::default()
+            }
+        }
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        let square = Square::try_from(number).ok()?;
+        MonoBitBoard::new(1 << square.index()).ok()
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        cell.square().ok().map(|square| square.to_number())
+    }
+
+    fn initial_pieces(player: Player) -> BitBoard {
+        match player {
+            Player::Red => INITIAL_RED_PIECES,
+            Player::Black => INITIAL_BLACK_PIECES,
+        }
+    }
+
+    fn promotion_squares(player: Player) -> BitBoard {
+        match player {
+            Player::Red => KING_SQUARES & TOP_SQUARES,
+            Player::Black => KING_SQUARES & BOTTOM_SQUARES,
+        }
+    }
+}
+
+/// The classical board mirrored, as used by Italian draughts, with a playable square in the top
+/// left corner. Squares are numbered from left to right starting from the top left corner of
+/// black's side of the board. Cells use the full 64 bits of a bitboard.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MirroredEightByEight;
+
+impl Topology for MirroredEightByEight {
+    type Square = Square;
+
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        // Shifting works regardless of which colour of squares is played on.
+        EightByEight::step(cell, direction)
+    }
+
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        EightByEight::shift(cells, direction)
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        let square = Square::try_from(number).ok()?;
+        Some(square.to_cell(Orientation::Mirrored))
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        let square = Square::from_cell(cell, Orientation::Mirrored).ok()?;
+        Some(square.to_number())
+    }
+
+    fn initial_pieces(player: Player) -> BitBoard {
+        match player {
+            Player::Red => squares::<Self>(21..=32),
+            Player::Black => squares::<Self>(1..=12),
+        }
+    }
+
+    fn promotion_squares(player: Player) -> BitBoard {
+        match player {
+            Player::Red => squares::<Self>(1..=4),
+            Player::Black => squares::<Self>(29..=32),
+        }
+    }
+}
+
+/// The mirrored board numbered from red's side, as used by Spanish and Portuguese draughts. Square
+/// 1 sits in the bottom right corner of red's side of the board and red starts on squares 1
+/// through 12. Cells use the full 64 bits of a bitboard.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MirroredFromRedEightByEight;
+
+impl Topology for MirroredFromRedEightByEight {
+    type Square = Square;
+
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        EightByEight::step(cell, direction)
+    }
+
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        EightByEight::shift(cells, direction)
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        let square = Square::try_from(number).ok()?;
+        Some(square.to_cell(Orientation::MirroredFromRed))
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        let square = Square::from_cell(cell, Orientation::MirroredFromRed).ok()?;
+        Some(square.to_number())
+    }
+
+    fn initial_pieces(player: Player) -> BitBoard {
+        match player {
+            Player::Red => squares::<Self>(1..=12),
+            Player::Black => squares::<Self>(21..=32),
+        }
+    }
+
+    fn promotion_squares(player: Player) -> BitBoard {
+        match player {
+            Player::Red => squares::<Self>(29..=32),
+            Player::Black => squares::<Self>(1..=4),
+        }
+    }
+}
+
+/// Retrieves the cells of the given square numbers on a topology.
+pub(crate) fn squares<T: Topology>(numbers: RangeInclusive<u8>) -> BitBoard {
+    numbers
+        .filter_map(T::cell)
+        .fold(BitBoard::new(0), |cells, cell| cells | cell)
+}
+
+/// Square board of the given width where every row holds `WIDTH / 2` playable squares. Squares
+/// are packed into the lowest bits of a bitboard so that square `n` is represented by bit `n - 1`.
+/// Square 1 sits on the second column of the top row, matching the numbering of the classical
+/// board. Orthogonal steps skip the cell that is not played on and land two cells away.
+pub(crate) struct PackedGrid<const WIDTH: u8>;
+
+impl<const WIDTH: u8> PackedGrid<WIDTH> {
+    const ROW_SQUARES: u8 = WIDTH / 2;
+    const SQUARES: BitBoard = packed_cells(WIDTH, None, None);
+    const EVEN_ROWS: BitBoard = packed_cells(WIDTH, Some(0), None);
+    const ODD_ROWS: BitBoard = packed_cells(WIDTH, Some(1), None);
+    // Squares on the leftmost and rightmost playable cell of their row.
+    const FIRST_SQUARES: BitBoard = packed_cells(WIDTH, None, Some(0));
+    const LAST_SQUARES: BitBoard = packed_cells(WIDTH, None, Some(WIDTH / 2 - 1));
+
+    pub(crate) fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        MonoBitBoard::try_from(Self::shift(BitBoard::from(cell), direction)).ok()
+    }
+
+    pub(crate) fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        // Neighbouring squares are offset differently on even rows, which start with an
+        // unplayable cell, and on odd rows, which start with a playable one.
+        let rows = Self::ROW_SQUARES;
+        let even = cells & Self::EVEN_ROWS;
+        let odd = cells & Self::ODD_ROWS;
+        let neighbours = match direction {
+            Direction::NorthWest => (even >> rows) | (odd & !Self::FIRST_SQUARES) >> (rows + 1),
+            Direction::NorthEast => (even & !Self::LAST_SQUARES) >> (rows - 1) | (odd >> rows),
+            Direction::SouthWest => (even << rows) | (odd & !Self::FIRST_SQUARES) << (rows - 1),
+            Direction::SouthEast => (even & !Self::LAST_SQUARES) << (rows + 1) | (odd << rows),
+            Direction::North => cells >> (2 * rows),
+            Direction::South => cells << (2 * rows),
+            Direction::East => (cells & !Self::LAST_SQUARES) << 1,
+            Direction::West => (cells & !Self::FIRST_SQUARES) >> 1,
+        };
+        neighbours & Self::SQUARES
+    }
+
+    pub(crate) fn cell(number: u8) -> Option<MonoBitBoard> {
+        if !(1..=Self::ROW_SQUARES * WIDTH).contains(&number) {
+            return None;
+        }
+        MonoBitBoard::new(1 << (number - 1)).ok()
+    }
+
+    pub(crate) fn number(cell: MonoBitBoard) -> Option<u8> {
+        let number = cell.value().trailing_zeros() as u8 + 1;
+        Self::SQUARES.contains(cell).then_some(number)
+    }
+
+    pub(crate) fn coordinates(cell: MonoBitBoard) -> Option<(u8, u8)> {
+        let index = Self::number(cell)? - 1;
+        let row = index / Self::ROW_SQUARES;
+        Some((row, 2 * (index % Self::ROW_SQUARES) + (row + 1) % 2))
+    }
+
+    pub(crate) fn cell_at(row: u8, column: u8) -> Option<MonoBitBoard> {
+        // Even rows start with an unplayable cell and odd rows with a playable one.
+        if row >= WIDTH || column >= WIDTH || column % 2 == row % 2 {
+            return None;
+        }
+        Self::cell(row * Self::ROW_SQUARES + column / 2 + 1)
+    }
+
+    /// Retrieves the playable squares of the given rows, counted from the top of the board.
+    pub(crate) fn rows(rows: std::ops::Range<u8>) -> BitBoard {
+        let row_mask = (1u128 << Self::ROW_SQUARES) - 1;
+        let cells = rows.fold(0, |cells, row| {
+            cells | row_mask << (row * Self::ROW_SQUARES)
+        });
+        BitBoard::new(cells)
+    }
+}
+
+// Builds the cells of a packed grid of the given width, optionally limited to the rows of the
+// given parity and to the given position within each row.
+const fn packed_cells(width: u8, row_parity: Option<u8>, position: Option<u8>) -> BitBoard {
+    let row_squares = width / 2;
+    let mut cells = 0;
+    let mut index = 0;
+    while index < row_squares * width {
+        let row = index / row_squares;
+        let row_matches = match row_parity {
+            Some(parity) => row % 2 == parity,
+            None => true,
+        };
+        let position_matches = match position {
+            Some(position) => index % row_squares == position,
+            None => true,
+        };
+        if row_matches && position_matches {
+            cells |= 1 << index;
+        }
+        index += 1;
+    }
+    BitBoard::new(cells)
+}
@@ -0,0 +1,189 @@
+use crate::bitboard::{
+    BitBoard, MonoBitBoard, BOTTOM_SQUARES, LEFT_SQUARES, RIGHT_SQUARES, TOP_SQUARES,
+};
+use crate::board::Player;
+use crate::position::square_type;
+use crate::rules::{PromotionRule, Rules};
+use crate::topology::{squares, Direction, Topology};
+
+square_type! {
+    /// Represents every square on a Turkish draughts board. Squares are numbered from left to right
+    /// starting from the top left corner of black's side of the board.
+    Square64, OrthogonalEightByEight => [
+        One,
+        Two,
+        Three,
+        Four,
+        Five,
+        Six,
+        Seven,
+        Eight,
+        Nine,
+        Ten,
+        Eleven,
+        Twelve,
+        Thirteen,
+        Fourteen,
+        Fifteen,
+        Sixteen,
+        Seventeen,
+        Eighteen,
+        Nineteen,
+        Twenty,
+        TwentyOne,
+        TwentyTwo,
+        TwentyThree,
+        TwentyFour,
+        TwentyFive,
+        TwentySix,
+        TwentySeven,
+        TwentyEight,
+        TwentyNine,
+        Thirty,
+        ThirtyOne,
+        ThirtyTwo,
+        ThirtyThree,
+        ThirtyFour,
+        ThirtyFive,
+        ThirtySix,
+        ThirtySeven,
+        ThirtyEight,
+        ThirtyNine,
+        Forty,
+        FortyOne,
+        FortyTwo,
+        FortyThree,
+        FortyFour,
+        FortyFive,
+        FortySix,
+        FortySeven,
+        FortyEight,
+        FortyNine,
+        Fifty,
+        FiftyOne,
+        FiftyTwo,
+        FiftyThree,
+        FiftyFour,
+        FiftyFive,
+        FiftySix,
+        FiftySeven,
+        FiftyEight,
+        FiftyNine,
+        Sixty,
+        SixtyOne,
+        SixtyTwo,
+        SixtyThree,
+        SixtyFour,
+    ]
+}
+
+/// The 8x8 board of Turkish draughts where every one of the 64 squares is played on and pieces
+/// travel orthogonally. Square 1 is represented by the highest bit of a bitboard, matching the
+/// layout of the classical board.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct OrthogonalEightByEight;
+
+impl Topology for OrthogonalEightByEight {
+    type Square = Square64;
+
+    fn step(cell: MonoBitBoard, direction: Direction) -> Option<MonoBitBoard> {
+        MonoBitBoard::try_from(Self::shift(BitBoard::from(cell), direction)).ok()
+    }
+
+    fn shift(cells: BitBoard, direction: Direction) -> BitBoard {
+        match direction {
+            Direction::North => (cells & !TOP_SQUARES) << 8,
+            Direction::West => (cells & !LEFT_SQUARES) << 1,
+            Direction::South => (cells & !BOTTOM_SQUARES) >> 8,
+            Direction::East => (cells & !RIGHT_SQUARES) >> 1,
+            Direction::NorthWest
+            | Direction::NorthEast
+            | Direction::SouthWest
+            | Direction::SouthEast => BitBoard::default(),
+        }
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        if !(1..=64).contains(&number) {
+            return None;
+        }
+        MonoBitBoard::new(1 << (64 - number)).ok()
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        let index = cell.value().trailing_zeros() as u8;
+        (index < 64).then_some(64 - index)
+    }
+
+    fn initial_pieces(player: Player) -> BitBoard {
+        match player {
+            Player::Red => squares::<Self>(41..=56),
+            Player::Black => squares::<Self>(9..=24),
+        }
+    }
+
+    fn promotion_squares(player: Player) -> BitBoard {
+        match player {
+            Player::Red => TOP_SQUARES,
+            Player::Black => BOTTOM_SQUARES,
+        }
+    }
+
+    fn directions() -> &'static [Direction] {
+        &Direction::ORTHOGONAL
+    }
+
+    fn forward_directions(player: Player) -> &'static [Direction] {
+        match player {
+            Player::Red => &[Direction::North, Direction::West, Direction::East],
+            Player::Black => &[Direction::South, Direction::West, Direction::East],
+        }
+    }
+}
+
+/// Rules of Turkish draughts, played on the [OrthogonalEightByEight] board with red taking the
+/// role of white and moving first. Men move and capture forward and sideways, kings fly, the turn
+/// capturing the most pieces must be taken and captured pieces are removed as soon as they are
+/// jumped. A capture sequence may not turn back in the direction it came from.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TurkishRules;
+
+impl Rules for TurkishRules {
+    type Topology = OrthogonalEightByEight;
+
+    fn first_player(&self) -> Player {
+        Player::Red
+    }
+
+    fn men_capture_backward(&self) -> bool {
+        false
+    }
+
+    fn flying_kings(&self) -> bool {
+        true
+    }
+
+    fn mandatory_capture(&self) -> bool {
+        true
+    }
+
+    fn majority_capture(&self) -> bool {
+        true
+    }
+
+    fn promotion_rule(&self) -> PromotionRule {
+        PromotionRule::ContinuesAsMan
+    }
+
+    fn immediate_capture_removal(&self) -> bool {
+        true
+    }
+
+    fn capture_reversal(&self) -> bool {
+        false
+    }
+
+    fn game_type(&self) -> Option<u8> {
+        Some(30)
+    }
+}
@@ -1,62 +1,703 @@
 use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::vec::IntoIter;
 
-use crate::position::{Move, NotationError};
+use crate::bitboard::{BitBoard, MonoBitBoard};
+use crate::board::BoardState;
+use crate::position::{Move, MoveError, MoveGenerator, MoveValidator, Notation, NotationError};
+use crate::rules::{CapturePriority, EnglishRules, PromotionRule, Rules};
+use crate::topology::{Direction, EightByEight, Topology};
 
 /// Represents a turn on a board. Turns are simply an abstraction around a collection of moves.
 /// Multiple moves are allowed per turn due to checkers allowing multiple jumps per turn.
-pub struct Turn {
+/// Turns are tied to the [Topology] of the board they are played on, which defaults to the
+/// classical [EightByEight] board.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Turn<T = EightByEight> {
     moves: Vec<Move>,
+    topology: PhantomData<T>,
 }
 
 impl Turn {
     /// Construct a turn from an iterable of items that can be converted into [Move] instances.
-    pub fn new<T, I>(moves: I) -> Result<Self, T::Error>
+    pub fn new<M, I>(moves: I) -> Result<Self, M::Error>
     where
-        T: TryInto<Move>,
-        I: IntoIterator<Item = T>,
+        M: TryInto<Move>,
+        I: IntoIterator<Item = M>,
+    {
+        Turn::from_moves(moves)
+    }
+
+    /// Attempts tp create a [Turn] instance using checkers notation.
+    pub fn from_notation(text: &str) -> Result<Self, NotationError> {
+        Turn::parse(text)
+    }
+
+    /// Attempts to create a [Turn] instance using checkers notation that is resolved against the
+    /// legal turns of the given board state according to [EnglishRules]. See [Turn::resolve].
+    pub fn resolve_notation(text: &str, board_state: &BoardState) -> Result<Self, NotationError> {
+        Turn::resolve(text, board_state, EnglishRules)
+    }
+}
+
+impl<T: Topology> Turn<T> {
+    /// Construct a turn played on the topology of this turn from an iterable of items that can be
+    /// converted into [Move] instances.
+    pub fn from_moves<M, I>(moves: I) -> Result<Self, M::Error>
+    where
+        M: TryInto<Move>,
+        I: IntoIterator<Item = M>,
     {
         let move_results = moves
             .into_iter()
             .map(|m| m.try_into())
-            .collect::<Result<Vec<Move>, T::Error>>();
+            .collect::<Result<Vec<Move>, M::Error>>();
 
-        move_results.map(|moves| Turn { moves })
+        move_results.map(Turn::with_moves)
     }
 
-    /// Attempts tp create a [Turn] instance using checkers notation.
-    pub fn from_notation(text: &str) -> Result<Self, NotationError> {
-        let parse_result = text
-            .split(',')
-            .map(Move::try_from)
-            .collect::<Result<Vec<Move>, NotationError>>();
+    /// Attempts to create a [Turn] instance using checkers notation, numbering squares according
+    /// to the topology of this turn. Moves are separated by commas, while multi-jumps may also be
+    /// written as a single chain of captures such as `9x18x27`.
+    pub fn parse(text: &str) -> Result<Self, NotationError> {
+        Turn::parse_with(text, Notation::Numeric)
+    }
+
+    /// Attempts to create a [Turn] instance using checkers notation that writes squares in the
+    /// given [Notation], such as `9x18x27` or `e3xg5xe7`. See [Turn::parse].
+    pub fn parse_with(text: &str, notation: Notation) -> Result<Self, NotationError> {
+        let mut moves = vec![];
+        for part in text.split(',') {
+            let squares = part.split(['x', 'X']).collect::<Vec<&str>>();
+            if squares.len() <= 2 {
+                moves.push(Move::parse_with::<T>(part, notation)?);
+                continue;
+            }
+            for pair in squares.windows(2) {
+                let text = format!("{}x{}", pair[0], pair[1]);
+                moves.push(Move::parse_with::<T>(&text, notation)?);
+            }
+        }
+
+        Ok(Turn::with_moves(moves))
+    }
 
-        parse_result.map(|moves| Turn { moves })
+    /// Attempts to create a [Turn] instance using checkers notation by finding the legal turn of
+    /// the given board state it describes, playing according to the given rules. Captures may be
+    /// shortened to the squares the capturing piece starts and ends on, such as `9x27`, with any
+    /// squares written in between narrowing down the path taken.
+    pub fn resolve<R: Rules<Topology = T>>(
+        text: &str,
+        board_state: &BoardState,
+        rules: R,
+    ) -> Result<Self, NotationError> {
+        Turn::resolve_with(text, board_state, rules, Notation::Numeric)
+    }
+
+    /// Attempts to create a [Turn] instance using checkers notation that writes squares in the
+    /// given [Notation] by finding the legal turn of the given board state it describes. See
+    /// [Turn::resolve].
+    pub fn resolve_with<R: Rules<Topology = T>>(
+        text: &str,
+        board_state: &BoardState,
+        rules: R,
+        notation: Notation,
+    ) -> Result<Self, NotationError> {
+        let written = Turn::<T>::parse_with(text, notation)?;
+        let written_cells = written.cells();
+        let capture = text.contains(['x', 'X']);
+
+        let mut turns = LegalTurnIter::with_rules(board_state, rules).filter(|turn| {
+            (capture_count(turn) > 0) == capture && follows_path(&turn.cells(), &written_cells)
+        });
+        let Some(turn) = turns.next() else {
+            return Err(NotationError::NoLegalTurn);
+        };
+        if turns.any(|other| other != turn) {
+            return Err(NotationError::AmbiguousTurn);
+        }
+        Ok(turn)
     }
 
     /// Returns a reference to all moves that represent this turn in the order they should be made.
     pub fn moves(&self) -> &Vec<Move> {
         &self.moves
     }
+
+    /// Writes the turn in standard checkers notation, listing every square the moving piece
+    /// visits. Squares are separated by crosses for captures, such as `9x18x27`, and by a dash
+    /// otherwise. Results in None if the turn visits a cell that is not a square of its topology.
+    pub fn notation(&self) -> Option<String> {
+        self.format_with(Notation::Numeric)
+    }
+
+    /// Writes the turn in checkers notation with squares written in the given [Notation], such
+    /// as `e3xg5xe7`, or None if the turn visits a cell that is not a square of its topology. See
+    /// [Turn::notation].
+    pub fn format_with(&self, notation: Notation) -> Option<String> {
+        let separator = if capture_count(self) > 0 { "x" } else { "-" };
+        let squares = self
+            .cells()
+            .into_iter()
+            .map(|cell| notation.format_cell::<T>(cell))
+            .collect::<Option<Vec<String>>>()?;
+        Some(squares.join(separator))
+    }
+
+    // Returns true if the given turn makes the same moves as this turn, regardless of the pieces
+    // the moves were written to capture.
+    pub(crate) fn is_played_as(&self, turn: &Turn<T>) -> bool {
+        self.moves.len() == turn.moves.len()
+            && self.moves.iter().zip(&turn.moves).all(|(m, other)| {
+                m.source() == other.source() && m.destination() == other.destination()
+            })
+    }
+
+    // Lists the cells visited by the moving piece, starting with the cell it moves from.
+    fn cells(&self) -> Vec<MonoBitBoard> {
+        let mut cells = vec![];
+        for m in &self.moves {
+            if cells.last() != Some(&m.source()) {
+                cells.push(m.source());
+            }
+            cells.push(m.destination());
+        }
+        cells
+    }
+
+    fn with_moves(moves: Vec<Move>) -> Self {
+        Turn {
+            moves,
+            topology: PhantomData,
+        }
+    }
 }
 
 /// Allows strings of checkers notation to be easily converted into turn instances.
-impl TryFrom<&str> for Turn {
+impl<T: Topology> TryFrom<&str> for Turn<T> {
     type Error = NotationError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Turn::from_notation(value)
+        Turn::parse(value)
+    }
+}
+
+/// Writes the turn in standard checkers notation. Cells that are not squares of the topology are
+/// written as their bit index between brackets, such as `[63]`, so that no cell goes missing.
+impl<T: Topology> Display for Turn<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let separator = if capture_count(self) > 0 { "x" } else { "-" };
+        let squares = self
+            .cells()
+            .into_iter()
+            .map(|cell| match T::number(cell) {
+                Some(number) => number.to_string(),
+                None => format!("[{}]", cell.value().trailing_zeros()),
+            })
+            .collect::<Vec<String>>();
+        write!(f, "{}", squares.join(separator))
     }
 }
 
 /// Allows array of any value that can be converted into a [Move]
 /// to be easily converted into turn instances.
-impl<T, const N: usize> TryFrom<[T; N]> for Turn
+#[allow(clippy::infallible_try_from)]
+impl<M, T: Topology, const N: usize> TryFrom<[M; N]> for Turn<T>
 where
-    T: Into<Move>,
+    M: Into<Move>,
 {
     type Error = Infallible;
 
-    fn try_from(value: [T; N]) -> Result<Self, Self::Error> {
-        Ok(Turn::new(value).unwrap())
+    fn try_from(value: [M; N]) -> Result<Self, Self::Error> {
+        let moves = value.into_iter().map(Into::into).collect();
+        Ok(Turn::with_moves(moves))
+    }
+}
+
+/// Tracks a turn that is being played. Captured pieces stay on the board until the turn is
+/// completed so that they block the capturing piece and can not be captured a second time.
+#[derive(Clone)]
+struct TurnProgress<R> {
+    board_state: BoardState,
+    rules: R,
+    captured: BitBoard,
+    moves: Vec<Move>,
+    crowned: bool,
+}
+
+impl<R: Rules> TurnProgress<R> {
+    fn new(board_state: &BoardState, rules: R) -> Self {
+        TurnProgress {
+            board_state: board_state.clone(),
+            rules,
+            captured: BitBoard::new(0),
+            moves: vec![],
+            crowned: false,
+        }
+    }
+
+    fn validator(&self) -> MoveValidator<'_, R> {
+        MoveValidator::with_rules(&self.board_state, self.rules).with_captured(self.captured)
+    }
+
+    fn apply(&mut self, m: Move) {
+        self.crowned = self.is_crowning_move(&m);
+        self.board_state.move_piece(&m);
+        if self.crowned && self.rules.promotion_rule() != PromotionRule::ContinuesAsMan {
+            self.board_state.crown(m.destination());
+        }
+        if let Some(capture) = m.capture() {
+            match self.rules.immediate_capture_removal() {
+                true => self.board_state.remove_pieces(BitBoard::from(capture)),
+                false => self.captured |= capture,
+            }
+        }
+        self.moves.push(m);
+    }
+
+    // Returns true if the given move continues the capture sequence in the direction opposite of
+    // the previous capture while the rules forbid doing so.
+    fn is_reversal(&self, m: &Move) -> bool {
+        if self.rules.capture_reversal() {
+            return false;
+        }
+        let Some(last) = self.moves.last() else {
+            return false;
+        };
+        let previous = Self::direction_of(last);
+        previous.is_some()
+            && previous.map(|direction| direction.opposite()) == Self::direction_of(m)
+    }
+
+    fn direction_of(m: &Move) -> Option<Direction> {
+        R::Topology::capture_directions()
+            .iter()
+            .copied()
+            .find(|direction| {
+                let mut current = m.source();
+                while let Some(next) = R::Topology::step(current, *direction) {
+                    if next == m.destination() {
+                        return true;
+                    }
+                    current = next;
+                }
+                false
+            })
+    }
+
+    fn is_crowning_move(&self, m: &Move) -> bool {
+        let player = self.board_state.current_player;
+        let promotion_squares = R::Topology::promotion_squares(player);
+        !self.board_state.is_king(m.source()) && promotion_squares.contains(m.destination())
+    }
+
+    fn is_ended_by_promotion(&self) -> bool {
+        self.crowned && self.rules.promotion_rule() == PromotionRule::EndsTurn
+    }
+
+    // Provides the captures the moving piece has to continue with. Empty once the turn is over.
+    fn continuations(&self) -> Vec<Move> {
+        self.completing(self.possible_continuations())
+    }
+
+    // Provides every capture the moving piece is able to continue with, including landings that
+    // would end the sequence while another landing along the same line continues it.
+    fn possible_continuations(&self) -> Vec<Move> {
+        match self.moves.last() {
+            Some(last) if last.capture().is_some() && !self.is_ended_by_promotion() => {
+                let mut captures = self.captures(last.destination());
+                captures.retain(|m| !self.is_reversal(m));
+                captures
+            }
+            _ => vec![],
+        }
+    }
+
+    fn captures(&self, cell: MonoBitBoard) -> Vec<Move> {
+        let player = self.board_state.current_player;
+        MoveGenerator::new(&self.board_state, player, self.rules)
+            .with_captured(self.captured)
+            .captures(cell)
+    }
+
+    // Drops the captures that stop short of completing the capture sequence. See
+    // [TurnProgress::stops_short].
+    fn completing(&self, captures: Vec<Move>) -> Vec<Move> {
+        captures
+            .iter()
+            .filter(|m| !self.stops_short(m, &captures))
+            .cloned()
+            .collect()
+    }
+
+    // Returns true if the given capture lands where the capture sequence ends while another of
+    // the given captures, jumping the same piece from the same square, lands where it continues.
+    // Flying kings are free to choose their landing only among the squares continuing the capture.
+    fn stops_short(&self, m: &Move, captures: &[Move]) -> bool {
+        let mut landings = captures
+            .iter()
+            .filter(|other| {
+                *other != m
+                    && other.source() == m.source()
+                    && other.capture().is_some()
+                    && other.capture() == m.capture()
+            })
+            .peekable();
+        landings.peek().is_some()
+            && !self.continues_with(m)
+            && landings.any(|other| self.continues_with(other))
+    }
+
+    fn continues_with(&self, m: &Move) -> bool {
+        let mut progress = self.clone();
+        progress.apply(m.clone());
+        !progress.possible_continuations().is_empty()
+    }
+
+    fn complete(mut self) -> (BoardState, Turn<R::Topology>) {
+        // Men passing through the king row are only crowned if the turn ends there.
+        if self.crowned && self.rules.promotion_rule() == PromotionRule::ContinuesAsMan {
+            if let Some(last) = self.moves.last() {
+                self.board_state.crown(last.destination());
+            }
+        }
+        self.board_state.remove_pieces(self.captured);
+        (self.board_state, Turn::with_moves(self.moves))
+    }
+}
+
+// Returns true if the given path starts and ends on the same cells as the written one, passing
+// through every cell written in between in the same order.
+fn follows_path(path: &[MonoBitBoard], written: &[MonoBitBoard]) -> bool {
+    let (Some(first), Some(last)) = (written.first(), written.last()) else {
+        return false;
+    };
+    if written.len() < 2 || path.first() != Some(first) || path.last() != Some(last) {
+        return false;
+    }
+
+    let mut between = path[1..path.len() - 1].iter();
+    written[1..written.len() - 1]
+        .iter()
+        .all(|cell| between.any(|visited| visited == cell))
+}
+
+fn capture_count<T: Topology>(turn: &Turn<T>) -> usize {
+    turn.moves()
+        .iter()
+        .filter(|m| m.capture().is_some())
+        .count()
+}
+
+// Scores a turn according to the given capture priority, higher scores being preferred.
+fn priority_score<T: Topology>(
+    priority: CapturePriority,
+    board_state: &BoardState,
+    turn: &Turn<T>,
+) -> usize {
+    let mut king_captures = turn.moves().iter().map(|m| {
+        m.capture()
+            .is_some_and(|capture| board_state.is_king(capture))
+    });
+
+    match priority {
+        CapturePriority::MostCaptures => capture_count(turn),
+        CapturePriority::CapturingKing => {
+            turn.moves()
+                .first()
+                .is_some_and(|m| board_state.is_king(m.source())) as usize
+        }
+        CapturePriority::MostKingsCaptured => king_captures.filter(|is_king| *is_king).count(),
+        CapturePriority::EarliestKingCaptured => king_captures
+            .position(|is_king| is_king)
+            .map_or(0, |index| turn.moves().len() - index),
+        CapturePriority::HighestValue { man, king } => king_captures
+            .zip(turn.moves())
+            .filter(|(_, m)| m.capture().is_some())
+            .map(|(is_king, _)| if is_king { king } else { man })
+            .sum(),
+    }
+}
+
+// Describes the error of a turn scoring lower than the required score of a capture priority.
+fn priority_error(priority: CapturePriority, required: usize) -> MoveError {
+    match priority {
+        CapturePriority::MostCaptures => MoveError::MajorityCaptureRequired { captures: required },
+        CapturePriority::CapturingKing => MoveError::KingCaptureRequired,
+        CapturePriority::MostKingsCaptured => {
+            MoveError::MostKingsCaptureRequired { kings: required }
+        }
+        CapturePriority::EarliestKingCaptured => MoveError::EarliestKingCaptureRequired,
+        CapturePriority::HighestValue { .. } => {
+            MoveError::HighestValueCaptureRequired { value: required }
+        }
+    }
+}
+
+/// Capable of validating that a given turn is valid provided additional [BoardState] context.
+/// Each move of the turn is validated with a [MoveValidator] against the state left behind by the
+/// previous move, while the turn as a whole must be made by a single piece and must complete any
+/// capture sequence it starts.
+pub struct TurnValidator<'a, R: Rules = EnglishRules> {
+    board_state: &'a BoardState,
+    rules: R,
+    legal_turns: Option<&'a [PlayedTurn<R::Topology>]>,
+}
+
+impl<'a> TurnValidator<'a> {
+    /// Creates a new [TurnValidator] instance from the given [BoardState] that validates turns
+    /// according to [EnglishRules].
+    pub fn new(board_state: &'a BoardState) -> Self {
+        TurnValidator::with_rules(board_state, EnglishRules)
+    }
+}
+
+impl<'a, R: Rules> TurnValidator<'a, R> {
+    /// Creates a new [TurnValidator] instance from the given [BoardState] that validates turns
+    /// according to the given rules.
+    pub fn with_rules(board_state: &'a BoardState, rules: R) -> Self {
+        TurnValidator {
+            board_state,
+            rules,
+            legal_turns: None,
+        }
+    }
+
+    /// Sets the legal turns of the board state, each paired with the state it leaves the board in,
+    /// so that they do not have to be generated again while validating.
+    pub(crate) fn with_legal_turns(mut self, legal_turns: &'a [PlayedTurn<R::Topology>]) -> Self {
+        self.legal_turns = Some(legal_turns);
+        self
+    }
+
+    /// Validates a given turn is valid per this validator's board state.
+    pub fn validate<T>(&self, turn: T) -> Result<(), MoveError>
+    where
+        T: TryInto<Turn<R::Topology>>,
+    {
+        let turn = turn
+            .try_into()
+            .map_err(|_| MoveError::InvalidConstruction)?;
+
+        self.play(&turn).map(|_| ())
+    }
+
+    /// Validates the given turn and returns the state the board is left in once every move of
+    /// the turn has been applied, along with the turn as it was resolved against the board. The
+    /// current player of the returned state is left untouched.
+    pub(crate) fn play(
+        &self,
+        turn: &Turn<R::Topology>,
+    ) -> Result<(BoardState, Turn<R::Topology>), MoveError> {
+        let mut progress = TurnProgress::new(self.board_state, self.rules);
+
+        for m in turn.moves() {
+            let validator = progress.validator();
+            let resolved = match progress.moves.last() {
+                Some(previous) => {
+                    if progress.is_ended_by_promotion() {
+                        return Err(MoveError::TurnEndedByPromotion);
+                    }
+                    TurnValidator::<R>::valid_continuation(previous, m)?;
+
+                    let resolved = validator.resolve(m)?;
+                    if resolved.capture().is_none() {
+                        return Err(MoveError::QuietMoveNotAlone);
+                    }
+                    if progress.is_reversal(&resolved) {
+                        return Err(MoveError::CaptureReversed);
+                    }
+                    resolved
+                }
+                None => {
+                    let resolved = validator.resolve(m)?;
+                    validator.valid_capture_obligation(&resolved)?;
+                    resolved
+                }
+            };
+            if resolved.capture().is_some()
+                && progress.stops_short(&resolved, &progress.captures(resolved.source()))
+            {
+                let piece =
+                    R::Topology::number(resolved.source()).ok_or(MoveError::InvalidConstruction)?;
+                return Err(MoveError::IncompleteCapture { piece });
+            }
+            progress.apply(resolved);
+        }
+
+        let Some(last) = progress.moves.last() else {
+            return Err(MoveError::EmptyTurn);
+        };
+        if !progress.continuations().is_empty() {
+            let piece =
+                R::Topology::number(last.destination()).ok_or(MoveError::InvalidConstruction)?;
+            return Err(MoveError::IncompleteCapture { piece });
+        }
+
+        let (board_state, turn) = progress.complete();
+        self.valid_capture_priorities(&turn)?;
+        Ok((board_state, turn))
+    }
+
+    fn valid_continuation(previous: &Move, m: &Move) -> Result<(), MoveError> {
+        if previous.destination() != m.source() {
+            return Err(MoveError::MixedPieces);
+        }
+        if previous.capture().is_none() {
+            return Err(MoveError::QuietMoveNotAlone);
+        }
+        Ok(())
+    }
+
+    fn valid_capture_priorities(&self, turn: &Turn<R::Topology>) -> Result<(), MoveError> {
+        let priorities = self.rules.capture_priorities();
+        if priorities.is_empty() || capture_count(turn) == 0 {
+            return Ok(());
+        }
+
+        // Every legal capturing turn is equal according to the capture priorities of the rules.
+        let best = match self.legal_turns {
+            Some(legal_turns) => legal_turns
+                .iter()
+                .map(|(best, _)| best)
+                .find(|best| capture_count(best) > 0)
+                .cloned(),
+            None => LegalTurnIter::with_rules(self.board_state, self.rules)
+                .find(|best| capture_count(best) > 0),
+        };
+        let Some(best) = best else {
+            return Ok(());
+        };
+        for priority in priorities {
+            let required = priority_score(*priority, self.board_state, &best);
+            if priority_score(*priority, self.board_state, turn) < required {
+                return Err(priority_error(*priority, required));
+            }
+        }
+        Ok(())
+    }
+}
+
+// A turn paired with the state the board is left in once it has been played, the current player
+// being left untouched.
+pub(crate) type PlayedTurn<T> = (Turn<T>, BoardState);
+
+/// Iterator capable of generating every complete and legal [Turn] for a given [BoardState].
+/// Unlike [crate::position::MoveIter], multi-jumps are followed until the capturing piece can no
+/// longer continue, yielding a separate turn for every capture path that branches off along the
+/// way.
+pub struct LegalTurnIter<T = EightByEight> {
+    turns: IntoIter<PlayedTurn<T>>,
+}
+
+impl LegalTurnIter {
+    /// Creates a new [LegalTurnIter] instance for the current player of the given board state
+    /// that generates turns according to [EnglishRules].
+    pub fn new(board_state: &BoardState) -> Self {
+        LegalTurnIter::with_rules(board_state, EnglishRules)
+    }
+}
+
+impl<T: Topology> LegalTurnIter<T> {
+    /// Creates a new [LegalTurnIter] instance for the current player of the given board state
+    /// that generates turns according to the given rules.
+    pub fn with_rules<R: Rules<Topology = T>>(board_state: &BoardState, rules: R) -> Self {
+        let player = board_state.current_player;
+        let pieces = board_state.current_player_pieces();
+        let moves = MoveGenerator::new(board_state, player, rules).legal_moves(pieces);
+        let moves = TurnProgress::new(board_state, rules).completing(moves);
+        let mut turns = Vec::with_capacity(moves.len());
+        for m in moves {
+            match m.capture() {
+                Some(_) => {
+                    let mut progress = TurnProgress::new(board_state, rules);
+                    progress.apply(m);
+                    LegalTurnIter::follow_captures(progress, &mut turns);
+                }
+                None => {
+                    let mut progress = TurnProgress::new(board_state, rules);
+                    progress.apply(m);
+                    let (state, turn) = progress.complete();
+                    turns.push((turn, state));
+                }
+            }
+        }
+
+        // Capture priorities only decide between capturing turns, quiet turns staying available
+        // whenever captures are not mandatory.
+        for priority in rules.capture_priorities() {
+            let score = |turn: &Turn<T>| priority_score(*priority, board_state, turn);
+            let best = turns
+                .iter()
+                .filter(|(turn, _)| capture_count(turn) > 0)
+                .map(|(turn, _)| score(turn))
+                .max()
+                .unwrap_or(0);
+            turns.retain(|(turn, _)| capture_count(turn) == 0 || score(turn) == best);
+        }
+
+        LegalTurnIter {
+            turns: turns.into_iter(),
+        }
+    }
+
+    // Creates an iterator over the given played turns.
+    pub(crate) fn from_played(turns: Vec<PlayedTurn<T>>) -> Self {
+        LegalTurnIter {
+            turns: turns.into_iter(),
+        }
+    }
+
+    // Collects every remaining turn paired with the state it leaves the board in.
+    pub(crate) fn into_played(self) -> Vec<PlayedTurn<T>> {
+        self.turns.collect()
+    }
+
+    // Keeps only the turns matching the given predicate.
+    pub(crate) fn retain(self, mut predicate: impl FnMut(&Turn<T>) -> bool) -> Self {
+        let mut turns = self.turns.collect::<Vec<PlayedTurn<T>>>();
+        turns.retain(|(turn, _)| predicate(turn));
+        LegalTurnIter {
+            turns: turns.into_iter(),
+        }
+    }
+
+    // Pairs every remaining turn with the state the board is left in once it has been played,
+    // the next player being the one to move.
+    pub(crate) fn successors(self) -> impl ExactSizeIterator<Item = (Turn<T>, BoardState)> {
+        self.turns.map(|(turn, mut board_state)| {
+            board_state.current_player = board_state.next_player();
+            (turn, board_state)
+        })
+    }
+
+    // Recursively extends the turn in progress with every capture the moving piece is able to
+    // continue with. Completed turns are pushed onto the given collection along with the state
+    // they leave the board in.
+    fn follow_captures<R: Rules<Topology = T>>(
+        progress: TurnProgress<R>,
+        turns: &mut Vec<PlayedTurn<T>>,
+    ) {
+        let continuations = progress.continuations();
+        if continuations.is_empty() {
+            let (board_state, turn) = progress.complete();
+            turns.push((turn, board_state));
+            return;
+        }
+
+        for m in continuations {
+            let mut progress = progress.clone();
+            progress.apply(m);
+            LegalTurnIter::follow_captures(progress, turns);
+        }
+    }
+}
+
+impl<T: Topology> Iterator for LegalTurnIter<T> {
+    type Item = Turn<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.turns.next().map(|(turn, _)| turn)
     }
 }
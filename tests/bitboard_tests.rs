@@ -1,7 +1,9 @@
+use std::collections::HashSet;
+
 use checke_rs::bitboard::{BitBoard, CellIter, MonoBitBoard};
 
 #[test]
-fn test_bitboard_equals_u64() {
+fn test_bitboard_equals_u128() {
     let value = 0b101;
     let bitboard = BitBoard::new(value);
     assert_eq!(bitboard, value)
@@ -58,3 +60,126 @@ fn test_cell_with_all_empty_cells() {
 
     assert_eq!(pieces.count(), 0)
 }
+
+#[test]
+fn test_bitboard_defaults_to_empty() {
+    assert!(BitBoard::default().empty());
+    assert_eq!(BitBoard::default(), BitBoard::new(0));
+}
+
+#[test]
+fn test_bitboard_operators() {
+    let bitboard = BitBoard::new(0b1100);
+    let other = BitBoard::new(0b1010);
+
+    assert_eq!(bitboard & other, 0b1000);
+    assert_eq!(bitboard | other, 0b1110);
+    assert_eq!(bitboard ^ other, 0b0110);
+    assert_eq!(!bitboard & BitBoard::new(0b1111), 0b0011);
+    assert_eq!(bitboard << 2, 0b110000);
+    assert_eq!(bitboard >> 2, 0b11);
+}
+
+#[test]
+fn test_bitboard_assign_operators() {
+    let cell = MonoBitBoard::new(0b1).unwrap();
+    let mut bitboard = BitBoard::new(0b1100);
+
+    bitboard |= cell;
+    assert_eq!(bitboard, 0b1101);
+    bitboard &= BitBoard::new(0b0111);
+    assert_eq!(bitboard, 0b0101);
+    bitboard <<= 1;
+    assert_eq!(bitboard, 0b1010);
+    bitboard >>= 3;
+    assert_eq!(bitboard, 0b1);
+    bitboard &= cell;
+    assert_eq!(bitboard, cell);
+}
+
+#[test]
+fn test_bitboard_const_operators() {
+    const BITBOARD: BitBoard = BitBoard::new(0b1100)
+        .and(BitBoard::new(0b0110))
+        .or(BitBoard::new(0b0001))
+        .xor(BitBoard::new(0b0011))
+        .shl(2)
+        .shr(1);
+    const INVERTED: BitBoard = BitBoard::new(0).not();
+
+    assert_eq!(BITBOARD, 0b1100);
+    assert_eq!(BITBOARD.value(), 0b1100);
+    assert_eq!(INVERTED, u128::MAX);
+}
+
+#[test]
+fn test_bitboard_count_ones() {
+    assert_eq!(BitBoard::new(0).count_ones(), 0);
+    assert_eq!(BitBoard::new(0b1011).count_ones(), 3);
+    assert_eq!(BitBoard::new(1 << 100 | 1).count_ones(), 2);
+}
+
+#[test]
+fn test_bitboard_lsb() {
+    let mut bitboard = BitBoard::new(0b1010_0000);
+
+    assert_eq!(bitboard.lsb(), Some(MonoBitBoard::new(0b10_0000).unwrap()));
+    assert_eq!(
+        bitboard.pop_lsb(),
+        Some(MonoBitBoard::new(0b10_0000).unwrap())
+    );
+    assert_eq!(
+        bitboard.pop_lsb(),
+        Some(MonoBitBoard::new(0b1000_0000).unwrap())
+    );
+    assert_eq!(bitboard.pop_lsb(), None);
+    assert_eq!(bitboard.lsb(), None);
+}
+
+#[test]
+fn test_bitboards_can_be_hashed() {
+    let bitboards = HashSet::from([
+        BitBoard::new(0b1),
+        BitBoard::new(0b1),
+        BitBoard::from(MonoBitBoard::new(0b10).unwrap()),
+    ]);
+
+    assert_eq!(bitboards.len(), 2);
+}
+
+#[test]
+fn test_cell_iter_is_exact_size() {
+    let bitboard = BitBoard::new(1 << 127 | 1 << 64 | 0b101);
+    let mut pieces = bitboard.used_cells();
+
+    assert_eq!(pieces.len(), 4);
+    pieces.next();
+    assert_eq!(pieces.len(), 3);
+    assert_eq!(pieces.size_hint(), (3, Some(3)));
+}
+
+#[test]
+fn test_cell_iter_iterates_in_reverse() {
+    let bitboard = BitBoard::new(1 << 127 | 1 << 64 | 0b101);
+
+    let pieces = bitboard.used_cells().rev().collect::<Vec<MonoBitBoard>>();
+
+    let expected_pieces = vec![
+        MonoBitBoard::new(1 << 127).unwrap(),
+        MonoBitBoard::new(1 << 64).unwrap(),
+        MonoBitBoard::new(0b100).unwrap(),
+        MonoBitBoard::new(0b001).unwrap(),
+    ];
+    assert_eq!(pieces, expected_pieces)
+}
+
+#[test]
+fn test_cell_iter_meets_in_the_middle() {
+    let mut pieces = BitBoard::new(0b111).used_cells();
+
+    assert_eq!(pieces.next(), Some(MonoBitBoard::new(0b001).unwrap()));
+    assert_eq!(pieces.next_back(), Some(MonoBitBoard::new(0b100).unwrap()));
+    assert_eq!(pieces.next(), Some(MonoBitBoard::new(0b010).unwrap()));
+    assert_eq!(pieces.next_back(), None);
+    assert_eq!(pieces.next(), None);
+}
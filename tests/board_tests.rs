@@ -1,9 +1,11 @@
 use checke_rs::bitboard::BitBoard;
 use checke_rs::board::{
-    Board, BoardBuilder, BoardCreationError, BoardState, BoardStatus, Player, INITIAL_KINGS,
-    INITIAL_RED_PIECES,
+    Board, BoardBuilder, BoardCreationError, BoardState, BoardStatus, DrawReason, Player,
+    DEFAULT_DRAW_TURN_LIMIT, INITIAL_KINGS, INITIAL_RED_PIECES,
 };
 use checke_rs::position::{MoveError, Square};
+use checke_rs::rules::{CustomRules, PromotionRule};
+use checke_rs::turn::Turn;
 
 #[test]
 #[ignore]
@@ -89,6 +91,24 @@ fn test_pop_turn_returns_none_when_no_turns_left_to_pop() {
     assert!(board_state.is_none())
 }
 
+#[test]
+fn test_played_turns_are_recorded() {
+    let mut board = Board::default();
+    board.push_turn("11-15").unwrap();
+    board.push_turn("23-19").unwrap();
+    assert!(board.push_turn("15-19").is_err());
+
+    assert_eq!(
+        board.turns(),
+        [
+            Turn::try_from("11-15").unwrap(),
+            Turn::try_from("23-19").unwrap()
+        ]
+    );
+    board.pop_turn();
+    assert_eq!(board.turns(), [Turn::try_from("11-15").unwrap()]);
+}
+
 #[test]
 fn test_push_turn_with_destination_occupied_error() {
     let mut board = Board::default();
@@ -160,3 +180,182 @@ fn test_board_builder_returns_error_when_multiple_placements_on_same_square() {
         BoardCreationError::DuplicateAssignments
     );
 }
+
+#[test]
+fn test_push_turn_with_capture_required_error() {
+    let mut board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+
+    let result = board.push_turn("1-5");
+
+    let error = result.expect_err("Expected error to occur when an available capture is ignored.");
+    assert_eq!(error, MoveError::CaptureRequired { pieces: vec![9] });
+    assert!(board.push_turn("9x18").is_ok());
+}
+
+#[test]
+fn test_push_turn_with_multiple_jumps() {
+    let mut board = BoardBuilder::default()
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .king(Player::Red, Square::TwentyThree)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    let board_state = board.push_turn("9x18,18x27").unwrap();
+
+    assert_eq!(board_state.current_player, Player::Red);
+    assert_eq!(
+        board_state.black_pieces,
+        BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000100_00000000)
+    );
+    assert_eq!(
+        board_state.red_pieces,
+        BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000010)
+    );
+    assert_eq!(board_state.kings, BitBoard::new(0));
+}
+
+#[test]
+fn test_push_turn_with_incomplete_capture_error() {
+    let mut board = BoardBuilder::default()
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    let result = board.push_turn("9x18");
+
+    let error = result.expect_err("Expected error to occur when a capture sequence is stopped.");
+    assert_eq!(error, MoveError::IncompleteCapture { piece: 18 });
+    assert_eq!(board.state_stack().len(), 1);
+}
+
+#[test]
+fn test_push_turn_crowns_and_ends_turn() {
+    let mut board = BoardBuilder::default()
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .build()
+        .unwrap();
+
+    let board_state = board.push_turn("22x31").unwrap();
+
+    let expected_kings =
+        BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00001000);
+    assert_eq!(board_state.all_kings(), expected_kings);
+    assert_eq!(board_state.current_player, Player::Red);
+}
+
+#[test]
+fn test_push_turn_continues_capture_after_crowning() {
+    let rules = CustomRules {
+        promotion_rule: PromotionRule::ContinuesAsKing,
+        ..CustomRules::default()
+    };
+    let mut board = BoardBuilder::with_rules(rules)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .piece(Player::Red, Square::One)
+        .build()
+        .unwrap();
+
+    let board_state = board.push_turn("22x31,31x24").unwrap();
+
+    let expected_kings =
+        BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000010_00000000_00000000);
+    assert_eq!(board_state.all_kings(), expected_kings);
+}
+
+fn shuffling_kings_board(draw_turn_limit: Option<usize>) -> Board {
+    BoardBuilder::default()
+        .draw_turn_limit(draw_turn_limit)
+        .king(Player::Black, Square::One)
+        .king(Player::Red, Square::ThirtyTwo)
+        .piece(Player::Red, Square::TwentyNine)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_threefold_repetition_is_draw() {
+    let mut board = shuffling_kings_board(None);
+
+    for turn in ["1-6", "32-27", "6-1", "27-32", "1-6", "32-27", "6-1"] {
+        board.push_turn(turn).unwrap();
+    }
+    assert_eq!(board.status(), BoardStatus::OnGoing);
+
+    board.push_turn("27-32").unwrap();
+    assert_eq!(
+        board.status(),
+        BoardStatus::Draw {
+            reason: DrawReason::ThreefoldRepetition
+        }
+    );
+
+    let result = board.push_turn("1-6");
+    assert_eq!(result.unwrap_err(), MoveError::GameConcluded);
+}
+
+#[test]
+fn test_turn_limit_is_draw() {
+    let mut board = shuffling_kings_board(Some(4));
+
+    for turn in ["1-6", "32-27", "6-10"] {
+        board.push_turn(turn).unwrap();
+    }
+    assert_eq!(board.quiet_turn_count(), 3);
+    assert_eq!(board.status(), BoardStatus::OnGoing);
+
+    board.push_turn("27-23").unwrap();
+    assert_eq!(
+        board.status(),
+        BoardStatus::Draw {
+            reason: DrawReason::TurnLimit
+        }
+    );
+}
+
+#[test]
+fn test_man_move_resets_turn_limit() {
+    let mut board = shuffling_kings_board(Some(4));
+
+    for turn in ["1-6", "32-27", "6-10", "29-25"] {
+        board.push_turn(turn).unwrap();
+    }
+
+    assert_eq!(board.quiet_turn_count(), 0);
+    assert_eq!(board.status(), BoardStatus::OnGoing);
+}
+
+#[test]
+fn test_draw_turn_limit_is_set_on_board() {
+    let mut board = Board::default();
+    assert_eq!(board.draw_turn_limit(), Some(DEFAULT_DRAW_TURN_LIMIT));
+
+    board.set_draw_turn_limit(None);
+    assert_eq!(board.draw_turn_limit(), None);
+}
+
+#[test]
+fn test_draw_turn_limit_applies_to_board_from_fen() {
+    let mut board = Board::from_fen("B:WK32:BK1").unwrap();
+    board.set_draw_turn_limit(Some(1));
+
+    board.push_turn("1-6").unwrap();
+    assert_eq!(
+        board.status(),
+        BoardStatus::Draw {
+            reason: DrawReason::TurnLimit
+        }
+    );
+}
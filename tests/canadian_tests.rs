@@ -0,0 +1,134 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardBuilder, Player};
+use checke_rs::canadian::{CanadianRules, Square72, TwelveByTwelve};
+use checke_rs::position::{Move, MoveError, NotationError};
+use checke_rs::topology::Topology;
+use checke_rs::turn::Turn;
+use test_case::test_case;
+
+#[test_case(Square72::One, 1 << 0)]
+#[test_case(Square72::FortyTwo, 1 << 41)]
+#[test_case(Square72::SeventyTwo, 1 << 71)]
+fn test_square_to_bitboard(square: Square72, expected: u128) {
+    assert_eq!(MonoBitBoard::from(square), expected);
+    assert_eq!(
+        Square72::try_from(MonoBitBoard::from(square)).unwrap(),
+        square
+    );
+}
+
+#[test]
+fn test_square_from_number() {
+    assert_eq!(Square72::try_from(72).unwrap(), Square72::SeventyTwo);
+    assert_eq!(
+        Square72::try_from(73).unwrap_err(),
+        NotationError::OutOfRange
+    );
+    assert_eq!(Square72::iter().count(), 72);
+}
+
+#[test]
+fn test_move_notation_is_parsed_on_twelve_by_twelve_board() {
+    let m = Move::parse::<TwelveByTwelve>("67x56").unwrap();
+
+    assert_eq!(m.source(), MonoBitBoard::from(Square72::SixtySeven));
+    assert_eq!(m.destination(), MonoBitBoard::from(Square72::FiftySix));
+    assert_eq!(m.capture(), Some(MonoBitBoard::from(Square72::SixtyOne)));
+    assert_eq!(
+        m,
+        Move::try_from((Square72::SixtySeven, Square72::FiftySix)).unwrap()
+    );
+    assert_eq!(
+        Move::parse::<TwelveByTwelve>("66-73").unwrap_err(),
+        NotationError::OutOfRange
+    );
+}
+
+#[test]
+fn test_initial_board() {
+    let board = Board::with_rules(CanadianRules);
+    let state = board.current_state();
+
+    assert_eq!(state.current_player, Player::Red);
+    assert_eq!(state.red_pieces().used_cells().count(), 30);
+    assert_eq!(state.black_pieces().used_cells().count(), 30);
+    assert!(state.is_red_piece(MonoBitBoard::from(Square72::FortyThree)));
+    assert!(state.is_red_piece(MonoBitBoard::from(Square72::SeventyTwo)));
+    assert!(state.is_black_piece(MonoBitBoard::from(Square72::Thirty)));
+    assert_eq!(board.legal_turns().count(), 11);
+}
+
+#[test]
+fn test_opening_turns() {
+    let mut board = Board::with_rules(CanadianRules);
+
+    assert!(board.push_turn("43-37").is_ok());
+    assert!(board.push_turn("26-32").is_ok());
+    assert_eq!(
+        board.push_turn("45-39").unwrap_err(),
+        MoveError::CaptureRequired { pieces: vec![37] }
+    );
+    assert!(board.push_turn("37x26").is_ok());
+}
+
+#[test]
+fn test_men_capture_backward() {
+    let mut board = BoardBuilder::with_rules(CanadianRules)
+        .piece(Player::Red, Square72::FortyFive)
+        .piece(Player::Black, Square72::FiftyOne)
+        .build()
+        .unwrap();
+
+    assert!(board.push_turn("45x58").is_ok());
+    assert!(board.current_state().black_pieces().empty());
+}
+
+#[test]
+fn test_kings_fly_across_the_board() {
+    let mut board = BoardBuilder::with_rules(CanadianRules)
+        .king(Player::Red, Square72::SixtySeven)
+        .piece(Player::Black, Square72::TwentyEight)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().collect::<Vec<Turn<TwelveByTwelve>>>();
+    assert_eq!(turns.len(), 4);
+    assert!(board.push_turn("67x6").is_ok());
+    assert!(board.current_state().black_pieces().empty());
+}
+
+#[test]
+fn test_maximum_capture_is_required() {
+    let mut board = BoardBuilder::with_rules(CanadianRules)
+        .piece(Player::Red, Square72::FortyFour)
+        .piece(Player::Red, Square72::FortyFive)
+        .piece(Player::Black, Square72::ThirtySeven)
+        .piece(Player::Black, Square72::TwentyFive)
+        .piece(Player::Black, Square72::FiftyOne)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("45x58").unwrap_err(),
+        MoveError::MajorityCaptureRequired { captures: 2 }
+    );
+    assert!(board.push_turn("44x31,31x20").is_ok());
+}
+
+#[test]
+fn test_man_ending_on_king_row_is_crowned() {
+    let mut board = BoardBuilder::with_rules(CanadianRules)
+        .piece(Player::Red, Square72::Seven)
+        .piece(Player::Black, Square72::SeventyTwo)
+        .build()
+        .unwrap();
+
+    let state = board.push_turn("7-1").unwrap();
+    assert!(state.is_king(MonoBitBoard::from(Square72::One)));
+    assert_eq!(
+        TwelveByTwelve::promotion_squares(Player::Red)
+            .used_cells()
+            .count(),
+        6
+    );
+}
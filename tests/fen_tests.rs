@@ -0,0 +1,117 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardBuilder, BoardState, Player};
+use checke_rs::fen::FenError;
+use checke_rs::international::{InternationalRules, Square50, TenByTen};
+use checke_rs::position::Square;
+use test_case::test_case;
+
+const INITIAL_FEN: &str = "B:W21,22,23,24,25,26,27,28,29,30,31,32:B1,2,3,4,5,6,7,8,9,10,11,12";
+
+#[test]
+fn test_position_is_read() {
+    let board_state = BoardState::from_fen("B:W18,24,27,K28:B12,16,K32").unwrap();
+
+    assert_eq!(board_state.current_player, Player::Black);
+    assert_eq!(board_state.red_pieces().used_cells().count(), 4);
+    assert_eq!(board_state.black_pieces().used_cells().count(), 3);
+    assert!(board_state.is_red_piece(MonoBitBoard::from(Square::Eighteen)));
+    assert!(board_state.is_black_piece(MonoBitBoard::from(Square::Twelve)));
+    assert!(board_state.is_king(MonoBitBoard::from(Square::TwentyEight)));
+    assert!(board_state.is_king(MonoBitBoard::from(Square::ThirtyTwo)));
+    assert_eq!(board_state.all_kings().used_cells().count(), 2);
+}
+
+#[test]
+fn test_position_matches_builder() {
+    let board = BoardBuilder::default()
+        .current_player(Player::Red)
+        .piece(Player::Red, Square::Eighteen)
+        .king(Player::Red, Square::TwentyEight)
+        .king(Player::Black, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        BoardState::from_fen("W:W18,K28:BK32").unwrap(),
+        *board.current_state()
+    );
+}
+
+#[test_case("B:W18,24,27,K28:B12,16,K32")]
+#[test_case("W:W18,K28:BK32")]
+#[test_case("W:W:B1")]
+#[test_case(INITIAL_FEN)]
+fn test_position_is_written_exactly(fen: &str) {
+    let board_state = BoardState::from_fen(fen).unwrap();
+
+    assert_eq!(board_state.to_fen(), fen);
+    assert_eq!(board_state.to_string(), fen);
+    assert_eq!(fen.parse::<BoardState>().unwrap(), board_state);
+}
+
+#[test_case("B:W21-32:B1-12" ; "ranges")]
+#[test_case("B:B1-12:W21-32" ; "black listed first")]
+#[test_case(" B : W21-32 : B1-4,5-8,9,10,11,12. " ; "whitespace and period")]
+fn test_alternative_forms_are_read(fen: &str) {
+    assert_eq!(BoardState::from_fen(fen).unwrap(), BoardState::default());
+    assert_eq!(BoardState::default().to_fen(), INITIAL_FEN);
+}
+
+#[test]
+fn test_played_positions_round_trip() {
+    let mut board = Board::default();
+    for _ in 0..60 {
+        let board_state = board.current_state();
+        assert_eq!(
+            BoardState::from_fen(&board_state.to_fen()).unwrap(),
+            *board_state
+        );
+
+        let Some(turn) = board.legal_turns().last() else {
+            break;
+        };
+        board.push_turn(turn).unwrap();
+    }
+}
+
+#[test]
+fn test_position_is_read_on_ten_by_ten_board() {
+    let board = Board::with_rules(InternationalRules);
+    let board_state = BoardState::parse_fen::<TenByTen>("W:W31-50:B1-20").unwrap();
+
+    assert_eq!(board_state, *board.current_state());
+    assert_eq!(
+        BoardState::parse_fen::<TenByTen>("B:WK46:B5")
+            .unwrap()
+            .format_fen::<TenByTen>(),
+        "B:WK46:B5"
+    );
+    assert!(BoardState::parse_fen::<TenByTen>("W:W50:B1")
+        .unwrap()
+        .is_red_piece(MonoBitBoard::from(Square50::Fifty)));
+}
+
+#[test_case("", FenError::InvalidPlayer(String::from("")) ; "empty")]
+#[test_case("X:W1:B2", FenError::InvalidPlayer(String::from("X")) ; "invalid player to move")]
+#[test_case("B:R1:B2", FenError::InvalidPlayer(String::from("R")) ; "invalid player of pieces")]
+#[test_case("B:W1", FenError::InvalidFormat ; "missing player")]
+#[test_case("B:W1:B2:W3", FenError::DuplicatePlayer(Player::Red) ; "duplicate player")]
+#[test_case("B:W1:B1", FenError::DuplicateSquare(1) ; "duplicate square")]
+#[test_case("B:W1-3:B2", FenError::DuplicateSquare(2) ; "duplicate square in range")]
+#[test_case("B:W33:B1", FenError::InvalidSquare(String::from("33")) ; "square out of range")]
+#[test_case("B:W8-5:B1", FenError::InvalidSquare(String::from("8-5")) ; "reversed range")]
+#[test_case("B:WK:B1", FenError::InvalidSquare(String::from("K")) ; "missing king square")]
+#[test_case("B:W1,,2:B3", FenError::InvalidSquare(String::from("")) ; "empty square")]
+fn test_invalid_positions(fen: &str, expected: FenError) {
+    assert_eq!(BoardState::from_fen(fen).unwrap_err(), expected);
+}
+
+#[test]
+fn test_error_message_names_square() {
+    let error = BoardState::from_fen("B:W1:B99").unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "'99' is not a square of the board being played on."
+    );
+}
@@ -0,0 +1,186 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardBuilder, BoardStatus, Player};
+use checke_rs::frisian::{FrisianRules, FrisianTenByTen};
+use checke_rs::international::Square50;
+use checke_rs::position::{Move, MoveError};
+use checke_rs::turn::Turn;
+use test_case::test_case;
+
+#[test]
+fn test_initial_board() {
+    let board = Board::with_rules(FrisianRules);
+    let state = board.current_state();
+
+    assert_eq!(state.current_player, Player::Red);
+    assert_eq!(state.red_pieces().used_cells().count(), 20);
+    assert_eq!(state.black_pieces().used_cells().count(), 20);
+    assert_eq!(board.legal_turns().count(), 9);
+}
+
+#[test]
+fn test_orthogonal_capture_notation_is_parsed() {
+    let m = Move::parse::<FrisianTenByTen>("28x8").unwrap();
+
+    assert_eq!(m.capture(), Some(MonoBitBoard::from(Square50::Eighteen)));
+}
+
+#[test_case(Square50::Eighteen, "28x8" ; "along a column")]
+#[test_case(Square50::TwentyNine, "28x30" ; "along a row")]
+fn test_men_capture_orthogonally(black: Square50, turn: &str) {
+    let mut board = BoardBuilder::with_rules(FrisianRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .piece(Player::Black, black)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 1);
+    assert!(board.push_turn(turn).is_ok());
+    assert!(board.current_state().black_pieces().empty());
+}
+
+#[test]
+fn test_men_move_diagonally() {
+    let board = BoardBuilder::with_rules(FrisianRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .piece(Player::Black, Square50::One)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 2);
+}
+
+#[test]
+fn test_kings_fly_orthogonally_when_capturing() {
+    let mut board = BoardBuilder::with_rules(FrisianRules)
+        .king(Player::Red, Square50::FortySix)
+        .piece(Player::Black, Square50::FortyEight)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().collect::<Vec<Turn<FrisianTenByTen>>>();
+    assert_eq!(turns.len(), 2);
+    assert!(board.push_turn("46x50").is_ok());
+    assert!(board.current_state().black_pieces().empty());
+}
+
+#[test]
+fn test_capturing_king_outweighs_capturing_man() {
+    let mut board = BoardBuilder::with_rules(FrisianRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .piece(Player::Red, Square50::FortySix)
+        .king(Player::Black, Square50::Eighteen)
+        .piece(Player::Black, Square50::FortySeven)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("46x48").unwrap_err(),
+        MoveError::HighestValueCaptureRequired { value: 3 }
+    );
+    assert!(board.push_turn("28x8").is_ok());
+}
+
+#[test]
+fn test_capturing_two_men_outweighs_capturing_king() {
+    let mut board = BoardBuilder::with_rules(FrisianRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .piece(Player::Red, Square50::FortySix)
+        .king(Player::Black, Square50::Eighteen)
+        .piece(Player::Black, Square50::FortySeven)
+        .piece(Player::Black, Square50::FortyTwo)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("28x8").unwrap_err(),
+        MoveError::HighestValueCaptureRequired { value: 4 }
+    );
+    assert!(board.push_turn("46x48,48x37").is_ok());
+}
+
+#[test]
+fn test_king_must_capture_when_values_are_equal() {
+    let mut board = BoardBuilder::with_rules(FrisianRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .king(Player::Red, Square50::FortySix)
+        .piece(Player::Black, Square50::Eighteen)
+        .piece(Player::Black, Square50::FortySeven)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("28x8").unwrap_err(),
+        MoveError::KingCaptureRequired
+    );
+    assert!(board.push_turn("46x48").is_ok());
+}
+
+fn king_move_limit_board(with_man: bool) -> Board<FrisianRules> {
+    let mut builder = BoardBuilder::with_rules(FrisianRules);
+    builder
+        .king(Player::Red, Square50::FortySix)
+        .king(Player::Black, Square50::One);
+    if with_man {
+        builder.piece(Player::Red, Square50::FortyFive);
+    }
+    let mut board = builder.build().unwrap();
+
+    for turn in ["46-41", "1-7", "41-46", "7-1", "46-41", "1-7"] {
+        board.push_turn(turn).unwrap();
+    }
+    board
+}
+
+#[test]
+fn test_king_move_limit() {
+    let mut board = king_move_limit_board(true);
+
+    assert_eq!(
+        board.consecutive_king_moves(MonoBitBoard::from(Square50::FortyOne)),
+        3
+    );
+    assert_eq!(
+        board.push_turn("41-46").unwrap_err(),
+        MoveError::KingMoveLimitReached {
+            piece: 41,
+            moves: 3
+        }
+    );
+    let turns = board.legal_turns().collect::<Vec<Turn<FrisianTenByTen>>>();
+    assert_eq!(turns, vec![Turn::try_from("45-40").unwrap()]);
+    assert!(board.push_turn("45-40").is_ok());
+}
+
+#[test]
+fn test_king_move_limit_requires_men() {
+    let mut board = king_move_limit_board(false);
+
+    assert!(board.push_turn("41-46").is_ok());
+}
+
+#[test]
+fn test_king_move_limit_ends_game_without_legal_turns() {
+    let mut board = BoardBuilder::with_rules(FrisianRules)
+        .king(Player::Red, Square50::Fifty)
+        .piece(Player::Red, Square50::Six)
+        .piece(Player::Black, Square50::One)
+        .king(Player::Black, Square50::TwentyThree)
+        .build()
+        .unwrap();
+
+    for turn in ["50-45", "23-19", "45-50", "19-23", "50-45", "23-19"] {
+        board.push_turn(turn).unwrap();
+    }
+
+    assert_eq!(board.legal_turns().count(), 0);
+    assert_eq!(
+        board.status(),
+        BoardStatus::Complete {
+            winner: Player::Black
+        }
+    );
+    assert_eq!(
+        board.push_turn("45-50").unwrap_err(),
+        MoveError::GameConcluded
+    );
+}
@@ -0,0 +1,154 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardBuilder, Player};
+use checke_rs::international::{InternationalRules, Square50, TenByTen};
+use checke_rs::position::{Move, MoveError, NotationError};
+use checke_rs::topology::Topology;
+use checke_rs::turn::Turn;
+use test_case::test_case;
+
+#[test_case(Square50::One, 1 << 0)]
+#[test_case(Square50::TwentyEight, 1 << 27)]
+#[test_case(Square50::Fifty, 1 << 49)]
+fn test_square_to_bitboard(square: Square50, expected: u128) {
+    assert_eq!(MonoBitBoard::from(square), expected);
+    assert_eq!(
+        Square50::try_from(MonoBitBoard::from(square)).unwrap(),
+        square
+    );
+}
+
+#[test]
+fn test_square_from_number() {
+    assert_eq!(Square50::try_from(50).unwrap(), Square50::Fifty);
+    assert_eq!(
+        Square50::try_from(51).unwrap_err(),
+        NotationError::OutOfRange
+    );
+    assert_eq!(Square50::iter().count(), 50);
+}
+
+#[test]
+fn test_move_notation_is_parsed_on_ten_by_ten_board() {
+    let m = Move::parse::<TenByTen>("32x23").unwrap();
+
+    assert_eq!(m.source(), MonoBitBoard::from(Square50::ThirtyTwo));
+    assert_eq!(m.destination(), MonoBitBoard::from(Square50::TwentyThree));
+    assert_eq!(m.capture(), Some(MonoBitBoard::from(Square50::TwentyEight)));
+    assert_eq!(
+        m,
+        Move::try_from((Square50::ThirtyTwo, Square50::TwentyThree)).unwrap()
+    );
+    assert_eq!(
+        Move::parse::<TenByTen>("46-51").unwrap_err(),
+        NotationError::OutOfRange
+    );
+}
+
+#[test]
+fn test_initial_board() {
+    let board = Board::with_rules(InternationalRules);
+    let state = board.current_state();
+
+    assert_eq!(state.current_player, Player::Red);
+    assert_eq!(state.red_pieces().used_cells().count(), 20);
+    assert_eq!(state.black_pieces().used_cells().count(), 20);
+    assert!(state.is_red_piece(MonoBitBoard::from(Square50::ThirtyOne)));
+    assert!(state.is_black_piece(MonoBitBoard::from(Square50::Twenty)));
+    assert_eq!(board.legal_turns().count(), 9);
+}
+
+#[test]
+fn test_opening_turns() {
+    let mut board = Board::with_rules(InternationalRules);
+
+    assert!(board.push_turn("32-28").is_ok());
+    assert!(board.push_turn("19-23").is_ok());
+    assert!(board.push_turn("28x19").is_ok());
+    assert_eq!(
+        board.push_turn("17-21").unwrap_err(),
+        MoveError::CaptureRequired {
+            pieces: vec![13, 14]
+        }
+    );
+}
+
+#[test]
+fn test_men_capture_backward() {
+    let mut board = BoardBuilder::with_rules(InternationalRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .piece(Player::Black, Square50::ThirtyThree)
+        .build()
+        .unwrap();
+
+    assert!(board.push_turn("28x39").is_ok());
+    assert!(board.current_state().black_pieces().empty());
+}
+
+#[test]
+fn test_kings_fly() {
+    let mut board = BoardBuilder::with_rules(InternationalRules)
+        .king(Player::Red, Square50::FortySix)
+        .piece(Player::Black, Square50::TwentyEight)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().collect::<Vec<Turn<TenByTen>>>();
+    assert_eq!(turns.len(), 5);
+    assert!(board.push_turn("46x5").is_ok());
+    assert!(board.current_state().black_pieces().empty());
+}
+
+#[test]
+fn test_maximum_capture_is_required() {
+    let mut board = BoardBuilder::with_rules(InternationalRules)
+        .piece(Player::Red, Square50::TwentyEight)
+        .piece(Player::Red, Square50::FortyFive)
+        .piece(Player::Black, Square50::TwentyThree)
+        .piece(Player::Black, Square50::Fourteen)
+        .piece(Player::Black, Square50::Forty)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("45x34").unwrap_err(),
+        MoveError::MajorityCaptureRequired { captures: 2 }
+    );
+    assert!(board.push_turn("28x19,19x10").is_ok());
+}
+
+#[test]
+fn test_man_passing_king_row_is_not_crowned() {
+    let mut board = BoardBuilder::with_rules(InternationalRules)
+        .piece(Player::Red, Square50::Fourteen)
+        .piece(Player::Black, Square50::Nine)
+        .piece(Player::Black, Square50::Eight)
+        .piece(Player::Black, Square50::ThirtySix)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("14x3").unwrap_err(),
+        MoveError::IncompleteCapture { piece: 3 }
+    );
+    let state = board.push_turn("14x3,3x12").unwrap();
+    assert!(state.is_red_piece(MonoBitBoard::from(Square50::Twelve)));
+    assert!(state.red_kings().empty());
+}
+
+#[test]
+fn test_man_ending_on_king_row_is_crowned() {
+    let mut board = BoardBuilder::with_rules(InternationalRules)
+        .piece(Player::Red, Square50::Ten)
+        .piece(Player::Black, Square50::ThirtySix)
+        .build()
+        .unwrap();
+
+    let state = board.push_turn("10-4").unwrap();
+    assert!(state.is_king(MonoBitBoard::from(Square50::Four)));
+    assert_eq!(
+        TenByTen::promotion_squares(Player::Red)
+            .used_cells()
+            .count(),
+        5
+    );
+}
@@ -0,0 +1,71 @@
+mod reference;
+
+use checke_rs::board::{BoardState, Player};
+use checke_rs::canadian::CanadianRules;
+use checke_rs::frisian::FrisianRules;
+use checke_rs::international::InternationalRules;
+use checke_rs::position::MoveIter;
+use checke_rs::rules::{
+    BrazilianRules, CustomRules, EnglishRules, GiveawayRules, ItalianRules, PoolRules, Rules,
+    RussianRules, SpanishRules,
+};
+use checke_rs::turkish::TurkishRules;
+use reference::baseline::baseline_moves;
+use reference::{per_square_moves, played_positions, MoveParts};
+use test_case::test_case;
+
+fn generated_moves<R: Rules>(board_state: &BoardState, player: Player, rules: R) -> Vec<MoveParts> {
+    MoveIter::with_rules(board_state, player, rules)
+        .map(|m| (m.source(), m.destination(), m.capture()))
+        .collect()
+}
+
+#[test_case(EnglishRules)]
+#[test_case(RussianRules)]
+#[test_case(ItalianRules)]
+#[test_case(BrazilianRules)]
+#[test_case(PoolRules)]
+#[test_case(SpanishRules)]
+#[test_case(GiveawayRules)]
+#[test_case(TurkishRules)]
+#[test_case(InternationalRules)]
+#[test_case(FrisianRules)]
+#[test_case(CanadianRules)]
+fn test_moves_match_per_square_generation<R: Rules>(rules: R) {
+    let positions = played_positions(rules, 8);
+    assert!(positions
+        .iter()
+        .any(|position| !position.all_kings().empty()));
+
+    for position in &positions {
+        for player in [Player::Red, Player::Black] {
+            assert_eq!(
+                generated_moves(position, player, rules),
+                per_square_moves(position, player, rules),
+                "moves of {player:?} differ in {position:?}"
+            );
+        }
+    }
+}
+
+// The original iterator only knew English checkers without mandatory captures, so it is compared
+// against the current iterator playing by the same rules. The original also accepted jumps over
+// empty squares, which are left out. Moves are compared regardless of the order they are
+// generated in, the original listing them by destination rather than captures first.
+#[test]
+fn test_moves_match_baseline_iterator() {
+    let rules = CustomRules {
+        mandatory_capture: false,
+        ..CustomRules::default()
+    };
+
+    for position in played_positions(EnglishRules, 8) {
+        let player = position.current_player;
+        let mut expected = baseline_moves(&position, player);
+        expected.retain(|(_, _, capture)| capture.is_none_or(|piece| position.is_piece(piece)));
+        let mut generated = generated_moves(&position, player, rules);
+        expected.sort_by_key(|(source, destination, _)| (source.value(), destination.value()));
+        generated.sort_by_key(|(source, destination, _)| (source.value(), destination.value()));
+        assert_eq!(generated, expected, "moves differ in {position:?}");
+    }
+}
@@ -0,0 +1,407 @@
+use checke_rs::board::{Board, BoardBuilder, Player};
+use checke_rs::fen::FenError;
+use checke_rs::international::{InternationalRules, Square50};
+use checke_rs::pdn::{Game, GameResult, PdnError, PdnErrorKind, VariantBoard};
+use checke_rs::position::{MoveError, NotationError, Square};
+use test_case::test_case;
+
+const GAME: &str = r#"[Event "Club Championship"]
+[Site "Glasgow \"Central\""]
+[Date "2023.06.01"]
+[Round "3"]
+[White "Ann"]
+[Black "Bob"]
+[Result "1-1"]
+
+{Old Fourteenth opening.}
+1. 11-15 23-19 2. 8-11 22-17 3. 4-8! {A solid developing move.} 17-13 (3... 25-22 4. 15-18 $14)
+4. 15-18?! ; Bold
+24-20 5. 9-14 19-15 6. 10x19 26-23 7. 19x26 30x23 1-1
+"#;
+
+#[test]
+fn test_tags_are_parsed() {
+    let game = Game::parse(GAME).unwrap();
+
+    assert_eq!(game.tags().len(), 7);
+    assert_eq!(game.tag("Event"), Some("Club Championship"));
+    assert_eq!(game.tag("Site"), Some("Glasgow \"Central\""));
+    assert_eq!(game.tag("Annotator"), None);
+}
+
+#[test]
+fn test_move_text_is_parsed() {
+    let game = Game::parse(GAME).unwrap();
+    let turns = game.turns();
+
+    assert_eq!(game.comments(), ["Old Fourteenth opening."]);
+    assert_eq!(turns.len(), 14);
+    assert_eq!(turns[0].squares(), [11, 15]);
+    assert!(!turns[0].is_capture());
+    assert_eq!(turns[10].notation(), "10x19");
+    assert!(turns[10].is_capture());
+    assert_eq!(game.result(), GameResult::Draw);
+}
+
+#[test]
+fn test_annotations_belong_to_preceding_turn() {
+    let game = Game::parse(GAME).unwrap();
+    let turns = game.turns();
+
+    assert_eq!(turns[4].nags(), [1]);
+    assert_eq!(turns[4].comments(), ["A solid developing move."]);
+    assert_eq!(turns[6].nags(), [6]);
+    assert_eq!(turns[6].comments(), ["Bold"]);
+}
+
+#[test]
+fn test_variations_are_parsed() {
+    let game = Game::parse(GAME).unwrap();
+    let variations = game.turns()[5].variations();
+
+    assert_eq!(variations.len(), 1);
+    let variation = &variations[0];
+    assert_eq!(variation.len(), 2);
+    assert_eq!(variation[0].notation(), "25-22");
+    assert_eq!(variation[1].nags(), [14]);
+}
+
+#[test]
+fn test_turns_record_their_position() {
+    let game = Game::parse(GAME).unwrap();
+
+    assert_eq!(game.turns()[0].position(), (10, 4));
+    assert_eq!(game.turns()[6].position(), (11, 4));
+}
+
+#[test]
+fn test_game_is_replayed() {
+    let game = Game::parse(GAME).unwrap();
+    let board = game.board().unwrap();
+    let state = board.current_state();
+
+    assert_eq!(board.state_stack().len(), 15);
+    assert_eq!(state.red_pieces().used_cells().count(), 10);
+    assert_eq!(state.black_pieces().used_cells().count(), 11);
+    assert_eq!(state.current_player, Player::Black);
+}
+
+#[test]
+fn test_multi_jump_is_replayed() {
+    let mut board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+    let game = Game::parse("1. 9x18x27 *").unwrap();
+
+    assert_eq!(game.turns()[0].squares(), [9, 18, 27]);
+    assert!(game.replay(&mut board).is_ok());
+    assert_eq!(board.current_state().red_pieces().used_cells().count(), 1);
+}
+
+#[test]
+fn test_every_game_is_parsed() {
+    let text = format!("{GAME}\n[Event \"Casual\"]\n1. 9-14 22-18 0-2\n\n1. 11-16 *");
+    let games = Game::parse_all(&text).unwrap();
+
+    assert_eq!(games.len(), 3);
+    assert_eq!(games[1].tag("Event"), Some("Casual"));
+    assert_eq!(games[1].turns().len(), 2);
+    assert_eq!(games[1].result(), GameResult::Win(Player::Black));
+    assert!(games[2].tags().is_empty());
+    assert_eq!(games[2].result(), GameResult::Unknown);
+}
+
+#[test]
+fn test_games_without_result_end_at_next_tags() {
+    let games = Game::parse_all("1. 9-14\n[Event \"Next\"]\n1. 11-15").unwrap();
+
+    assert_eq!(games.len(), 2);
+    assert_eq!(games[0].result(), GameResult::Unknown);
+    assert_eq!(games[1].tag("Event"), Some("Next"));
+}
+
+#[test_case("2-0", GameResult::Win(Player::Red))]
+#[test_case("1-0", GameResult::Win(Player::Red))]
+#[test_case("0-2", GameResult::Win(Player::Black))]
+#[test_case("0-1", GameResult::Win(Player::Black))]
+#[test_case("1-1", GameResult::Draw)]
+#[test_case("1/2-1/2", GameResult::Draw)]
+#[test_case("*", GameResult::Unknown)]
+fn test_result_is_parsed(result: &str, expected: GameResult) {
+    let game = Game::parse(&format!("1. 11-15 {result}")).unwrap();
+
+    assert_eq!(game.result(), expected);
+}
+
+#[test_case("1. 11-15 {unclosed", 1, 10, PdnErrorKind::UnclosedComment ; "unclosed comment")]
+#[test_case("1. 11-15\n23-19 (2. 8-11", 2, 7, PdnErrorKind::UnclosedVariation ; "unclosed variation")]
+#[test_case("1. 11-15 &", 1, 10, PdnErrorKind::UnexpectedCharacter('&') ; "unexpected character")]
+#[test_case("1. 11-15-19", 1, 4, PdnErrorKind::InvalidTurn(String::from("11-15-19")) ; "invalid turn")]
+#[test_case("1. 11-0", 1, 4, PdnErrorKind::InvalidTurn(String::from("11-0")) ; "invalid square")]
+#[test_case("1. 11-15 !!!", 1, 10, PdnErrorKind::InvalidAnnotation(String::from("!!!")) ; "invalid annotation")]
+#[test_case("(1. 11-15)", 1, 1, PdnErrorKind::MissingTurn ; "variation without turn")]
+#[test_case("[Event Casual]", 1, 8, PdnErrorKind::InvalidTag ; "unquoted tag value")]
+#[test_case("[Event \"Casual", 1, 15, PdnErrorKind::UnexpectedEnd ; "unclosed tag value")]
+fn test_parse_errors(text: &str, line: usize, column: usize, kind: PdnErrorKind) {
+    assert_eq!(
+        Game::parse(text).unwrap_err(),
+        PdnError { line, column, kind }
+    );
+}
+
+#[test]
+fn test_replay_errors_point_at_turn() {
+    let game = Game::parse("1. 11-15 23-19\n2. 8-11 23-18 *").unwrap();
+
+    assert_eq!(
+        game.board().unwrap_err(),
+        PdnError {
+            line: 2,
+            column: 9,
+            kind: PdnErrorKind::IllegalTurn(MoveError::NoPieceAtSource),
+        }
+    );
+}
+
+#[test]
+fn test_error_message_includes_position() {
+    let error = Game::parse("1. 11-15 {unclosed").unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Line 1, column 10: Comment was never closed."
+    );
+}
+
+#[test]
+fn test_game_is_written_from_board() {
+    let mut board = Board::default();
+    for turn in ["11-15", "23-19", "8-11"] {
+        board.push_turn(turn).unwrap();
+    }
+
+    assert_eq!(
+        Game::from_board(&board).to_string(),
+        "[Event \"?\"]\n[Site \"?\"]\n[Date \"????.??.??\"]\n[Round \"?\"]\n[White \"?\"]\n\
+         [Black \"?\"]\n[Result \"*\"]\n[GameType \"21\"]\n\n1. 11-15 23-19 2. 8-11 *\n"
+    );
+}
+
+#[test]
+fn test_multi_jump_is_written_with_every_square() {
+    let mut board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+    board.push_turn("9x18,18x27").unwrap();
+    let game = Game::from_board(&board);
+
+    assert_eq!(game.turns()[0].notation(), "9x18x27");
+    assert_eq!(game.result(), GameResult::Win(Player::Black));
+    assert_eq!(game.tag("Result"), Some("0-2"));
+    assert!(game.to_string().ends_with("\n1. 9x18x27 0-2\n"));
+}
+
+#[test]
+fn test_flying_king_capture_is_written_and_replayed() {
+    let mut builder = BoardBuilder::with_rules(InternationalRules);
+    builder
+        .king(Player::Red, Square50::FortySix)
+        .piece(Player::Black, Square50::TwentyThree)
+        .piece(Player::Black, Square50::Five);
+    let mut board = builder.build().unwrap();
+    board.push_turn("46x19").unwrap();
+    let game = Game::parse(&Game::from_board(&board).to_string()).unwrap();
+
+    assert_eq!(game.turns()[0].notation(), "46x19");
+    let mut replayed = builder.build().unwrap();
+    assert!(game.replay(&mut replayed).is_ok());
+    assert_eq!(replayed.current_state(), board.current_state());
+}
+
+#[test]
+fn test_game_type_is_written_and_read_back() {
+    let mut board = Board::with_rules(InternationalRules);
+    board.push_turn("32-28").unwrap();
+    let game = Game::parse(&Game::from_board(&board).to_string()).unwrap();
+
+    assert_eq!(game.tag("GameType"), Some("20"));
+    assert_eq!(game.game_type(), Ok(20));
+    let Ok(VariantBoard::International(replayed)) = game.variant_board() else {
+        panic!("Game should be replayed on an international board");
+    };
+    assert_eq!(replayed.current_state(), board.current_state());
+}
+
+#[test_case("1. 11-15 *", 21)]
+#[test_case("[GameType \"20,W,10,10,N2,0\"]\n*", 20)]
+#[test_case("[GameType \"40\"]\n*", 40)]
+fn test_game_type_is_read(text: &str, expected: u8) {
+    assert_eq!(Game::parse(text).unwrap().game_type(), Ok(expected));
+}
+
+#[test]
+fn test_unsupported_game_type_is_rejected() {
+    let game = Game::parse("[GameType \"1\"]\n*").unwrap();
+
+    assert_eq!(
+        game.variant_board().unwrap_err(),
+        PdnError {
+            line: 1,
+            column: 1,
+            kind: PdnErrorKind::UnsupportedGameType(String::from("1")),
+        }
+    );
+}
+
+#[test]
+fn test_written_game_is_read_back() {
+    let game = Game::parse(GAME).unwrap();
+    let text = game.to_string();
+
+    assert!(
+        text.contains("3. 4-8 $1\n{A solid developing move.} 3... 17-13 (3... 25-22 4. 15-18 $14)")
+    );
+    assert!(text.lines().all(|line| line.len() <= 80));
+    assert_eq!(Game::parse(&text).unwrap().to_string(), text);
+}
+
+#[test]
+fn test_comment_before_variation_is_read_back() {
+    let game = Game::parse("1. 11-15 ( {Also played.} 1. 9-13 ) 23-19 *").unwrap();
+    let variation = &game.turns()[0].variations()[0];
+
+    assert_eq!(variation[0].preceding_comments(), ["Also played."]);
+    assert!(variation[0].comments().is_empty());
+
+    let text = game.to_string();
+    assert_eq!(text, "1. 11-15 ({Also played.} 1. 9-13) 1... 23-19 *\n");
+    let read_back = Game::parse(&text).unwrap();
+    let variation = &read_back.turns()[0].variations()[0];
+    assert_eq!(variation[0].preceding_comments(), ["Also played."]);
+    assert_eq!(read_back.to_string(), text);
+}
+
+#[test_case("1-0" ; "white win")]
+#[test_case("0-1" ; "black win")]
+#[test_case("1/2-1/2" ; "draw")]
+#[test_case("2-0" ; "white win in draughts notation")]
+fn test_result_is_written_as_read(result: &str) {
+    let game = Game::parse(&format!("1. 11-15 {result}")).unwrap();
+
+    assert_eq!(game.to_string(), format!("1. 11-15 {result}\n"));
+}
+
+#[test]
+fn test_tags_are_set_and_escaped() {
+    let mut game = Game::from_board(&Board::default());
+    game.set_tag("Event", "Club \"Open\"")
+        .set_tag("Annotator", "C:\\games");
+
+    assert_eq!(game.tags().len(), 9);
+    let text = game.to_string();
+    assert!(text.starts_with("[Event \"Club \\\"Open\\\"\"]\n"));
+    assert!(text.contains("[Annotator \"C:\\\\games\"]\n"));
+    assert_eq!(
+        Game::parse(&text).unwrap().tag("Event"),
+        Some("Club \"Open\"")
+    );
+}
+
+#[test]
+fn test_game_is_replayed_from_setup() {
+    let text = "[SetUp \"1\"]\n[FEN \"W:W22:B9,K6\"]\n\n1... 22-18 2. 9-14 *\n";
+    let game = Game::parse(text).unwrap();
+    let board = game.board().unwrap();
+
+    assert_eq!(board.initial_state().to_fen(), "W:W22:BK6,9");
+    assert_eq!(board.current_state().to_fen(), "W:W18:BK6,14");
+    assert!(Game::from_board(&board)
+        .to_string()
+        .ends_with("\n1... 22-18 2. 9-14 *\n"));
+}
+
+#[test]
+fn test_replay_follows_draw_turn_limit_of_board() {
+    let text = "[SetUp \"1\"]\n[FEN \"B:WK32:BK1\"]\n\n1. 1-6 32-27 2. 6-10 27-23 *";
+    let game = Game::parse(text).unwrap();
+
+    let mut board = game.setup().unwrap();
+    board.set_draw_turn_limit(Some(3));
+    assert_eq!(
+        game.replay(&mut board).unwrap_err(),
+        PdnError {
+            line: 4,
+            column: 22,
+            kind: PdnErrorKind::IllegalTurn(MoveError::GameConcluded),
+        }
+    );
+
+    let mut board = game.setup().unwrap();
+    board.set_draw_turn_limit(None);
+    game.replay(&mut board).unwrap();
+    assert_eq!(board.current_state().to_fen(), "B:WK23:BK10");
+}
+
+#[test]
+fn test_setup_is_written_from_board() {
+    let mut board = BoardBuilder::default()
+        .current_player(Player::Red)
+        .piece(Player::Red, Square::TwentyTwo)
+        .piece(Player::Black, Square::Nine)
+        .king(Player::Black, Square::Six)
+        .build()
+        .unwrap();
+    board.push_turn("22-18").unwrap();
+    let game = Game::from_board(&board);
+
+    assert_eq!(game.tag("SetUp"), Some("1"));
+    assert_eq!(game.tag("FEN"), Some("W:W22:BK6,9"));
+    assert!(game.to_string().ends_with("\n1... 22-18 *\n"));
+    assert_eq!(Game::from_board(&Board::default()).tag("FEN"), None);
+}
+
+#[test]
+fn test_setup_errors_point_at_tag() {
+    let game = Game::parse("[Event \"Casual\"]\n [FEN \"W:W33:B1\"]\n*").unwrap();
+
+    assert_eq!(
+        game.board().unwrap_err(),
+        PdnError {
+            line: 2,
+            column: 2,
+            kind: PdnErrorKind::Fen(FenError::InvalidSquare(String::from("33"))),
+        }
+    );
+}
+
+#[test]
+fn test_shortened_capture_is_replayed() {
+    let text = "[FEN \"B:W14,23,32:B1,9\"]\n1. 9x27 32x23 *";
+    let board = Game::parse(text).unwrap().board().unwrap();
+
+    assert_eq!(board.turns()[0].to_string(), "9x18x27");
+    assert_eq!(board.current_state().to_fen(), "B:W23:B1");
+}
+
+#[test]
+fn test_ambiguous_capture_is_error() {
+    let game = Game::parse("[FEN \"B:W14,15,22,23:B10\"]\n1. 10x26 *").unwrap();
+
+    assert_eq!(
+        game.board().unwrap_err(),
+        PdnError {
+            line: 2,
+            column: 4,
+            kind: PdnErrorKind::Notation(NotationError::AmbiguousTurn),
+        }
+    );
+}
@@ -0,0 +1,226 @@
+use checke_rs::board::{Board, BoardBuilder, BoardState, Player};
+use checke_rs::canadian::CanadianRules;
+use checke_rs::frisian::FrisianRules;
+use checke_rs::international::InternationalRules;
+use checke_rs::perft::{divide, perft, perft_with_rules};
+use checke_rs::position::Square;
+use checke_rs::rules::{
+    CustomRules, EnglishRules, ItalianRules, PromotionRule, Rules, RussianRules,
+};
+use checke_rs::turkish::TurkishRules;
+use checke_rs::turn::Turn;
+use test_case::test_case;
+
+// Counts the leaf nodes of the game tree by playing every legal turn on the board itself, which
+// validates each turn in full before it is applied.
+fn board_perft<R: Rules>(board: &mut Board<R>, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut nodes = 0;
+    for turn in board.legal_turns().collect::<Vec<Turn<R::Topology>>>() {
+        board.push_turn(turn).unwrap();
+        nodes += board_perft(board, depth - 1);
+        board.pop_turn();
+    }
+    nodes
+}
+
+// Counts the leaf nodes of the game tree the way published draughts perft numbers do, where
+// captures taking the same pieces to the same square along different routes are a single move.
+// Such captures leave the board in the same position, so positions repeated among the turns of a
+// node are only counted once.
+fn distinct_perft<R: Rules>(board: &mut Board<R>, depth: usize) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut positions = vec![];
+    let mut nodes = 0;
+    for turn in board.legal_turns().collect::<Vec<Turn<R::Topology>>>() {
+        board.push_turn(turn).unwrap();
+        if !positions.contains(board.current_state()) {
+            positions.push(board.current_state().clone());
+            nodes += distinct_perft(board, depth - 1);
+        }
+        board.pop_turn();
+    }
+    nodes
+}
+
+// Published by Aart Bik in "Computing Deep Perft and Divide Numbers for Checkers", ICGA Journal
+// 35(4), 2012.
+#[test_case(0, 1)]
+#[test_case(1, 7)]
+#[test_case(2, 49)]
+#[test_case(3, 302)]
+#[test_case(4, 1469)]
+#[test_case(5, 7361)]
+#[test_case(6, 36768)]
+#[test_case(7, 179740)]
+#[test_case(8, 845931)]
+fn test_english_initial_position(depth: usize, expected: u64) {
+    assert_eq!(perft(&BoardState::default(), depth), expected);
+}
+
+// Published for international draughts by engine authors comparing their generators on the
+// damforum.nl draughts forum.
+#[test_case(1, 9)]
+#[test_case(2, 81)]
+#[test_case(3, 658)]
+#[test_case(4, 4265)]
+#[test_case(5, 27117)]
+#[test_case(6, 167140)]
+fn test_international_initial_position(depth: usize, expected: u64) {
+    let board = Board::with_rules(InternationalRules);
+
+    assert_eq!(
+        perft_with_rules(board.current_state(), depth, InternationalRules),
+        expected
+    );
+}
+
+// The Woldouby position, a well known test of capture generation in international draughts with
+// white to move. Published on the damforum.nl draughts forum along with the initial position.
+#[test_case(1, 6)]
+#[test_case(2, 12)]
+#[test_case(3, 30)]
+#[test_case(4, 73)]
+#[test_case(5, 215)]
+#[test_case(6, 590)]
+#[test_case(7, 1944)]
+#[test_case(8, 6269)]
+#[test_case(9, 22369)]
+fn test_international_woldouby_position(depth: usize, expected: u64) {
+    let fen = "W:W25,27,28,30,32,33,34,35,37,38:B12,13,14,16,18,19,21,23,24,26";
+    let mut board = Board::from_fen_with_rules(fen, InternationalRules).unwrap();
+
+    assert_eq!(distinct_perft(&mut board, depth), expected);
+}
+
+// Counted by hand rather than with the generator under test:
+// 1. Black must take 9x18x27. The man on 1 has no capture, and 27 can not jump the piece on 32.
+// 2. Red must take 32x23, jumping back over the black man that landed on 27.
+// 3. Black's only piece left, on 1, steps to 5 or 6.
+// 4. Red's man on 23 steps to 18 or 19 in either position, far from the black man.
+#[test]
+fn test_mandatory_double_jump() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    let nodes = (1..=4)
+        .map(|depth| perft(board.current_state(), depth))
+        .collect::<Vec<u64>>();
+    assert_eq!(nodes, vec![1, 1, 2, 4]);
+}
+
+// Counted by hand rather than with the generator under test. Black must take 22x31, crowning
+// on 31.
+// - A man continuing past the king row has nothing left to jump. Red's man on 27 then steps to
+//   23 or 24, and in both positions the black king steps to 26 or 27.
+// - A crowned king continues with 31x24 over 27, leaving red without pieces.
+#[test_case(PromotionRule::EndsTurn, vec![1, 2, 4])]
+#[test_case(PromotionRule::ContinuesAsMan, vec![1, 2, 4])]
+#[test_case(PromotionRule::ContinuesAsKing, vec![1, 0, 0])]
+fn test_promotion_during_capture(promotion_rule: PromotionRule, expected: Vec<u64>) {
+    let rules = CustomRules {
+        promotion_rule,
+        ..CustomRules::default()
+    };
+    let board = BoardBuilder::with_rules(rules)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .build()
+        .unwrap();
+
+    let nodes = (1..=3)
+        .map(|depth| perft_with_rules(board.current_state(), depth, rules))
+        .collect::<Vec<u64>>();
+    assert_eq!(nodes, expected);
+}
+
+// Counted by hand rather than with the generator under test: the king on 18 jumps to 9, 11, 25 or
+// 27, and no landing square offers a second jump.
+#[test]
+fn test_king_capturing_in_every_direction() {
+    let board = BoardBuilder::default()
+        .king(Player::Black, Square::Eighteen)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::Fifteen)
+        .piece(Player::Red, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    assert_eq!(perft(board.current_state(), 1), 4);
+}
+
+// Counted by hand rather than with the generator under test: the man on 10 reaches 26 either
+// through 17 or through 19, and each path counts as its own turn. In both positions red has two
+// men left, each of which steps forward to two empty squares.
+#[test]
+fn test_capture_paths_ending_on_same_square() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::Ten)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::Fifteen)
+        .piece(Player::Red, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    let nodes = (1..=2)
+        .map(|depth| perft(board.current_state(), depth))
+        .collect::<Vec<u64>>();
+    assert_eq!(nodes, vec![2, 8]);
+}
+
+#[test]
+fn test_divide_breaks_count_down_by_turn() {
+    let board_state = BoardState::default();
+    let counts = divide(&board_state, 3);
+
+    assert_eq!(counts.len(), 7);
+    assert_eq!(counts.iter().map(|(_, nodes)| nodes).sum::<u64>(), 302);
+    assert!(counts.iter().all(|(turn, _)| turn.moves().len() == 1));
+    assert!(divide(&board_state, 0).is_empty());
+}
+
+#[test]
+fn test_divide_ends_with_single_nodes() {
+    let counts = divide(&BoardState::default(), 1);
+
+    assert!(counts.iter().all(|(_, nodes)| *nodes == 1));
+    assert_eq!(
+        counts
+            .into_iter()
+            .map(|(turn, _)| turn)
+            .collect::<Vec<Turn>>(),
+        BoardState::default().legal_turns().collect::<Vec<Turn>>()
+    );
+}
+
+#[test_case(EnglishRules, 5)]
+#[test_case(RussianRules, 5)]
+#[test_case(ItalianRules, 5)]
+#[test_case(TurkishRules, 4)]
+#[test_case(InternationalRules, 4)]
+#[test_case(FrisianRules, 4)]
+#[test_case(CanadianRules, 3)]
+fn test_perft_matches_played_turns<R: Rules>(rules: R, depth: usize) {
+    let mut board = Board::with_rules(rules);
+    let board_state = board.current_state().clone();
+
+    assert_eq!(
+        perft_with_rules(&board_state, depth, rules),
+        board_perft(&mut board, depth)
+    );
+}
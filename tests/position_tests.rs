@@ -1,6 +1,11 @@
 use checke_rs::bitboard::MonoBitBoard;
-use checke_rs::board::{BoardState, Player};
-use checke_rs::position::{Move, MoveError, MoveIter, MoveValidator, Square};
+use checke_rs::board::{BoardBuilder, BoardState, Player};
+use checke_rs::international::TenByTen;
+use checke_rs::position::{
+    Move, MoveError, MoveIter, MoveValidator, Notation, NotationError, Square,
+};
+use checke_rs::topology::{EightByEight, Topology};
+use test_case::test_case;
 
 #[test]
 fn test_capturing_move_creates_capture() {
@@ -21,6 +26,31 @@ fn test_non_capturing_move_does_not_create_capture() {
     assert!(capture.is_none())
 }
 
+#[test_case(Square::One, Square::Ten, Some(Square::Six))]
+#[test_case(Square::Ten, Square::One, Some(Square::Six))]
+#[test_case(Square::One, Square::Six, None)]
+#[test_case(Square::Nine, Square::Eighteen, Some(Square::Fourteen))]
+fn test_move_from_cells_captures_jumped_square(
+    source: Square,
+    destination: Square,
+    expected: Option<Square>,
+) {
+    let m = Move::new(MonoBitBoard::from(source), MonoBitBoard::from(destination));
+
+    assert_eq!(m.capture(), expected.map(MonoBitBoard::from));
+}
+
+#[test]
+fn test_move_from_cells_of_other_topology_captures_jumped_square() {
+    let source = TenByTen::cell(32).unwrap();
+    let destination = TenByTen::cell(23).unwrap();
+
+    let m = Move::on::<TenByTen>(source, destination);
+
+    assert_eq!(m.capture(), TenByTen::cell(28));
+    assert_eq!(m, Move::parse::<TenByTen>("32x23").unwrap());
+}
+
 #[test]
 fn test_no_moves_are_generated_from_empty_board() {
     let board_state = BoardState::empty();
@@ -71,3 +101,120 @@ fn test_selecting_source_with_no_piece_is_error() {
     let err = result.expect_err("Expected error when no piece was selected.");
     assert_eq!(err, MoveError::NoPieceAtSource);
 }
+
+#[test]
+fn test_capture_over_empty_square_is_error() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::TwentyNine)
+        .build()
+        .unwrap();
+    let validator = MoveValidator::new(board.current_state());
+
+    let result = validator.validate("9x18");
+
+    let err = result.expect_err("Expected error when capturing an empty square.");
+    assert_eq!(err, MoveError::InvalidCapture);
+}
+
+#[test]
+fn test_non_capturing_move_is_error_when_capture_is_available() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Black, Square::Ten)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+    let validator = MoveValidator::new(board.current_state());
+
+    let result = validator.validate("1-6");
+
+    let err = result.expect_err("Expected error when a capture was ignored.");
+    assert_eq!(
+        err,
+        MoveError::CaptureRequired {
+            pieces: vec![9, 10]
+        }
+    );
+    assert!(validator.validate("9x18").is_ok());
+    assert!(validator.validate("10x17").is_ok());
+}
+
+#[test]
+fn test_move_iter_only_yields_captures_when_capture_is_available() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+    let board_state = board.current_state();
+
+    let moves = MoveIter::new(board_state, board_state.current_player).collect::<Vec<Move>>();
+
+    assert_eq!(
+        moves,
+        vec![Move::from_squares(Square::Nine, Square::Eighteen)]
+    );
+}
+
+#[test]
+fn test_move_iter_yields_every_move_of_each_piece() {
+    let board_state = BoardState::default();
+
+    let moves = MoveIter::new(&board_state, board_state.current_player);
+
+    assert_eq!(moves.count(), 7)
+}
+
+#[test_case("c3-d4", "22-18")]
+#[test_case("e3xg5", "23x16")]
+#[test_case("b8-a7", "1-5")]
+#[test_case("g1-h2", "32-28")]
+fn test_algebraic_move_matches_numeric_move(algebraic: &str, numeric: &str) {
+    let m = Move::parse_with::<EightByEight>(algebraic, Notation::Algebraic).unwrap();
+
+    assert_eq!(m, Move::from_notation(numeric).unwrap());
+    assert_eq!(
+        m.format_with::<EightByEight>(Notation::Algebraic),
+        Some(String::from(algebraic))
+    );
+    assert_eq!(
+        m.format_with::<EightByEight>(Notation::Numeric),
+        Some(String::from(numeric))
+    );
+}
+
+#[test]
+fn test_algebraic_capture_creates_capture() {
+    let m = Move::parse_with::<EightByEight>("e3xg5", Notation::Algebraic).unwrap();
+
+    assert_eq!(m.capture(), Some(MonoBitBoard::from(Square::Nineteen)));
+}
+
+#[test]
+fn test_algebraic_move_on_ten_by_ten_board() {
+    let m = Move::parse_with::<TenByTen>("d4-e5", Notation::Algebraic).unwrap();
+
+    assert_eq!(m, Move::parse::<TenByTen>("32-28").unwrap());
+    assert_eq!(
+        m.format_with::<TenByTen>(Notation::Algebraic),
+        Some(String::from("d4-e5"))
+    );
+}
+
+#[test_case("c3d4", Notation::Algebraic, NotationError::InvalidFormat ; "missing separator")]
+#[test_case("C3-D4", Notation::Algebraic, NotationError::InvalidFormat ; "upper case columns")]
+#[test_case("c0-d4", Notation::Algebraic, NotationError::InvalidFormat ; "row zero")]
+#[test_case("22-18", Notation::Algebraic, NotationError::InvalidFormat ; "numeric squares")]
+#[test_case("c3-d4", Notation::Numeric, NotationError::InvalidFormat ; "algebraic squares")]
+#[test_case("a8-b7", Notation::Algebraic, NotationError::OutOfRange ; "unplayable square")]
+#[test_case("c9-d8", Notation::Algebraic, NotationError::OutOfRange ; "row outside of the board")]
+#[test_case("i1-h2", Notation::Algebraic, NotationError::OutOfRange ; "column outside of the board")]
+fn test_invalid_algebraic_moves(text: &str, notation: Notation, expected: NotationError) {
+    assert_eq!(
+        Move::parse_with::<EightByEight>(text, notation).unwrap_err(),
+        expected
+    );
+}
@@ -0,0 +1,193 @@
+//! Copy of the move iterator checke-rs shipped with before moves of all pieces were generated at
+//! once with bitboard shifts. Candidate destinations are looked up in per-square tables one piece
+//! at a time and every candidate is run through a validator, exactly as the original `MoveIter`,
+//! `MoveGenerator` and `MoveValidator` did. Only used to check and benchmark the current generator
+//! against, and only knows English checkers.
+
+use checke_rs::bitboard::{BitBoard, MonoBitBoard};
+use checke_rs::board::{BoardState, Player};
+use checke_rs::position::Square;
+
+use super::MoveParts;
+
+const RED_PIECE_MOVES: &[BitBoard; 32] = &[
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b01000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b01010000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00010100_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000101_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00010000_10100000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b01000100_00101000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00010001_00001010_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000100_00000010_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00100000_01000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_10001000_01010000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00100010_00010100_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00001000_00000101_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00010000_10100000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_01000100_00101000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00010001_00001010_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000100_00000010_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00100000_01000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_10001000_01010000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00100010_00010100_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00001000_00000101_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00010000_10100000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_01000100_00101000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00010001_00001010_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000100_00000010_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100000_01000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10001000_01010000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100010_00010100_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00001000_00000101_00000000),
+];
+
+const BLACK_PIECE_MOVES: &[BitBoard; 32] = &[
+    BitBoard::new(0b00000000_10100000_00010000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00101000_01000100_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00001010_00010001_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000010_00000100_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_01000000_00100000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_01010000_10001000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00010100_00100010_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000101_00001000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_10100000_00010000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00101000_01000100_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00001010_00010001_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000010_00000100_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_01000000_00100000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_01010000_10001000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00010100_00100010_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000101_00001000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10100000_00010000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00101000_01000100_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00001010_00010001_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000010_00000100_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_01000000_00100000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_01010000_10001000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00010100_00100010),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000101_00001000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_10100000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00101000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00001010),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000010),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00000000),
+];
+
+const KING_MOVES: &[BitBoard; 32] = &[
+    BitBoard::new(0b00000000_10100000_00010000_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00101000_01000100_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00001010_00010001_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00000010_00000100_00000000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b01000000_00000000_01000000_00100000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b01010000_00000000_01010000_10001000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00010100_00000000_00010100_00100010_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00000101_00000000_00000101_00001000_00000000_00000000_00000000_00000000),
+    BitBoard::new(0b00010000_10100000_00000000_10100000_00010000_00000000_00000000_00000000),
+    BitBoard::new(0b01000100_00101000_00000000_00101000_01000100_00000000_00000000_00000000),
+    BitBoard::new(0b00010001_00001010_00000000_00001010_00010001_00000000_00000000_00000000),
+    BitBoard::new(0b00000100_00000010_00000000_00000010_00000100_00000000_00000000_00000000),
+    BitBoard::new(0b00000000_00100000_01000000_00000000_01000000_00100000_00000000_00000000),
+    BitBoard::new(0b00000000_10001000_01010000_00000000_01010000_10001000_00000000_00000000),
+    BitBoard::new(0b00000000_00100010_00010100_00000000_00010100_00100010_00000000_00000000),
+    BitBoard::new(0b00000000_00001000_00000101_00000000_00000101_00001000_00000000_00000000),
+    BitBoard::new(0b00000000_00000000_00010000_10100000_00000000_10100000_00010000_00000000),
+    BitBoard::new(0b00000000_00000000_01000100_00101000_00000000_00101000_01000100_00000000),
+    BitBoard::new(0b00000000_00000000_00010001_00001010_00000000_00001010_00010001_00000000),
+    BitBoard::new(0b00000000_00000000_00000100_00000010_00000000_00000010_00000100_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00100000_01000000_00000000_01000000_00100000),
+    BitBoard::new(0b00000000_00000000_00000000_10001000_01010000_00000000_01010000_10001000),
+    BitBoard::new(0b00000000_00000000_00000000_00100010_00010100_00000000_00010100_00100010),
+    BitBoard::new(0b00000000_00000000_00000000_00001000_00000101_00000000_00000101_00001000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00010000_10100000_00000000_10100000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_01000100_00101000_00000000_00101000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00010001_00001010_00000000_00001010),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000100_00000010_00000000_00000010),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100000_01000000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_10001000_01010000_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00100010_00010100_00000000),
+    BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00001000_00000101_00000000),
+];
+
+/// Generates the moves of the given player the way the original `MoveIter` did. Like the
+/// original, captures are not mandatory and the validator checks candidates against the current
+/// player of the board state, so the given player is expected to be the current player.
+pub fn baseline_moves(board_state: &BoardState, player: Player) -> Vec<MoveParts> {
+    board_state
+        .pieces_by_player(player)
+        .used_cells()
+        .flat_map(|piece| by_cell(board_state, board_state.current_player, piece))
+        .filter(|m| validate(board_state, m))
+        .collect()
+}
+
+// Provides the candidate moves of a given cell, as `MoveGenerator::by_cell` did.
+fn by_cell(board_state: &BoardState, player: Player, cell: MonoBitBoard) -> Vec<MoveParts> {
+    let Ok(square) = Square::try_from(cell) else {
+        return vec![];
+    };
+
+    let move_index = (square.to_number() - 1) as usize;
+    let move_bitboard = match board_state.is_king(cell) {
+        true => KING_MOVES[move_index],
+        false => match player {
+            Player::Red => RED_PIECE_MOVES[move_index],
+            Player::Black => BLACK_PIECE_MOVES[move_index],
+        },
+    };
+    move_bitboard
+        .used_cells()
+        .map(|destination| (cell, destination, get_capture(cell, destination)))
+        .collect()
+}
+
+// Derives the captured cell from the distance between source and destination, as `Move::new`
+// did.
+fn get_capture(source: MonoBitBoard, destination: MonoBitBoard) -> Option<MonoBitBoard> {
+    let mut distance = 0;
+    let (mut source, destination) = if source > destination {
+        (source, destination)
+    } else {
+        (destination, source)
+    };
+
+    while source != destination {
+        source >>= 1;
+        distance += 1;
+    }
+
+    let is_capture_move = distance > 9;
+    match is_capture_move {
+        true => {
+            source <<= distance / 2;
+            Some(source)
+        }
+        false => None,
+    }
+}
+
+// Validates a candidate move, as `MoveValidator::validate` did.
+fn validate(board_state: &BoardState, m: &MoveParts) -> bool {
+    let (source, destination, capture) = *m;
+    if !board_state.is_piece(source) || !board_state.is_current_player_piece(source) {
+        return false;
+    }
+
+    let candidates = by_cell(board_state, board_state.current_player, source);
+    if candidates.iter().all(|(_, dest, _)| *dest != destination) {
+        return false;
+    }
+    if board_state.is_piece(destination) {
+        return false;
+    }
+    match capture {
+        Some(piece) => !board_state.is_current_player_piece(piece),
+        None => true,
+    }
+}
@@ -0,0 +1,154 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardState, Player};
+use checke_rs::rules::Rules;
+use checke_rs::topology::{Direction, Topology};
+use checke_rs::turn::Turn;
+
+pub mod baseline;
+
+/// Parts of a move: its source, destination and captured piece. Long quiet moves of flying kings
+/// can not be built as a [checke_rs::position::Move] from outside the crate without being mistaken
+/// for captures, so moves are compared by their parts instead.
+pub type MoveParts = (MonoBitBoard, MonoBitBoard, Option<MonoBitBoard>);
+
+/// Generates moves the way they were generated before moves of all pieces were calculated at
+/// once: one piece at a time, stepping a square at a time in every direction the piece travels.
+/// Serves as the baseline the shift-based generator of [checke_rs::position::MoveIter] is checked
+/// and benchmarked against.
+pub fn per_square_moves<R: Rules>(
+    board_state: &BoardState,
+    player: Player,
+    rules: R,
+) -> Vec<MoveParts> {
+    let pieces = board_state.pieces_by_player(player);
+    let captures = pieces
+        .used_cells()
+        .map(|piece| captures::<R>(board_state, player, rules, piece))
+        .collect::<Vec<Vec<MoveParts>>>();
+    let capture_required = rules.mandatory_capture() && captures.iter().any(|c| !c.is_empty());
+
+    let mut moves = vec![];
+    for (piece, captures) in pieces.used_cells().zip(captures) {
+        moves.extend(captures);
+        if !capture_required {
+            moves.extend(quiet_moves::<R>(board_state, player, rules, piece));
+        }
+    }
+    moves
+}
+
+/// Collects the positions of several games played by picking turns with a fixed pseudo-random
+/// sequence, so that positions with kings, multi-jumps and crowded boards are all covered.
+pub fn played_positions<R: Rules>(rules: R, games: u64) -> Vec<BoardState> {
+    let mut positions = vec![];
+    for game in 0..games {
+        let mut board = Board::with_rules(rules);
+        let mut seed = game;
+        for _ in 0..150 {
+            positions.push(board.current_state().clone());
+            let mut turns = board.legal_turns().collect::<Vec<Turn<R::Topology>>>();
+            if turns.is_empty() {
+                break;
+            }
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let turn = turns.swap_remove((seed >> 33) as usize % turns.len());
+            if board.push_turn(turn).is_err() {
+                break;
+            }
+        }
+    }
+    positions
+}
+
+fn quiet_moves<R: Rules>(
+    board_state: &BoardState,
+    player: Player,
+    rules: R,
+    piece: MonoBitBoard,
+) -> Vec<MoveParts> {
+    let directions = match board_state.is_king(piece) {
+        true => R::Topology::directions(),
+        false => R::Topology::forward_directions(player),
+    };
+
+    let mut moves = vec![];
+    for direction in directions {
+        let mut current = piece;
+        while let Some(next) = R::Topology::step(current, *direction) {
+            if board_state.is_piece(next) {
+                break;
+            }
+            moves.push((piece, next, None));
+            if !is_flying(board_state, rules, piece) {
+                break;
+            }
+            current = next;
+        }
+    }
+    moves
+}
+
+fn captures<R: Rules>(
+    board_state: &BoardState,
+    player: Player,
+    rules: R,
+    piece: MonoBitBoard,
+) -> Vec<MoveParts> {
+    let directions = match board_state.is_king(piece) || rules.men_capture_backward() {
+        true => R::Topology::capture_directions(),
+        false => R::Topology::forward_directions(player),
+    };
+
+    let mut moves = vec![];
+    for direction in directions {
+        let Some(target) = capture_target::<R>(board_state, player, rules, piece, *direction)
+        else {
+            continue;
+        };
+
+        let mut current = target;
+        while let Some(landing) = R::Topology::step(current, *direction) {
+            if board_state.is_piece(landing) {
+                break;
+            }
+            moves.push((piece, landing, Some(target)));
+            if !is_flying(board_state, rules, piece) {
+                break;
+            }
+            current = landing;
+        }
+    }
+    moves
+}
+
+// Finds the piece a capture in the given direction would jump, skipping over empty squares when
+// the piece is a flying king.
+fn capture_target<R: Rules>(
+    board_state: &BoardState,
+    player: Player,
+    rules: R,
+    piece: MonoBitBoard,
+    direction: Direction,
+) -> Option<MonoBitBoard> {
+    let mut current = piece;
+    while let Some(next) = R::Topology::step(current, direction) {
+        if board_state.is_piece(next) {
+            let is_opponent = !board_state.pieces_by_player(player).contains(next);
+            let is_protected = board_state.is_king(next)
+                && !board_state.is_king(piece)
+                && !rules.men_capture_kings();
+            return (is_opponent && !is_protected).then_some(next);
+        }
+        if !is_flying(board_state, rules, piece) {
+            return None;
+        }
+        current = next;
+    }
+    None
+}
+
+fn is_flying<R: Rules>(board_state: &BoardState, rules: R, piece: MonoBitBoard) -> bool {
+    rules.flying_kings() && board_state.is_king(piece)
+}
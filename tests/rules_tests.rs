@@ -0,0 +1,192 @@
+use checke_rs::bitboard::BitBoard;
+use checke_rs::board::{Board, BoardBuilder, Player};
+use checke_rs::position::{Move, MoveError, MoveIter, MoveValidator, Square};
+use checke_rs::rules::{CustomRules, EnglishRules, Rules};
+use checke_rs::topology::EightByEight;
+use checke_rs::turn::{LegalTurnIter, Turn, TurnValidator};
+
+fn custom_rules() -> CustomRules {
+    CustomRules::default()
+}
+
+#[test]
+fn test_custom_rules_default_to_english_rules() {
+    let rules = custom_rules();
+    let english = EnglishRules;
+
+    assert_eq!(rules.first_player(), english.first_player());
+    assert_eq!(rules.men_capture_backward(), english.men_capture_backward());
+    assert_eq!(rules.flying_kings(), english.flying_kings());
+    assert_eq!(rules.mandatory_capture(), english.mandatory_capture());
+    assert_eq!(rules.majority_capture(), english.majority_capture());
+    assert_eq!(rules.promotion_rule(), english.promotion_rule());
+    assert_eq!(rules.men_capture_kings(), english.men_capture_kings());
+    assert_eq!(
+        rules.immediate_capture_removal(),
+        english.immediate_capture_removal()
+    );
+    assert_eq!(rules.capture_reversal(), english.capture_reversal());
+    assert_eq!(rules.win_condition(), english.win_condition());
+    assert_eq!(rules.king_move_limit(), english.king_move_limit());
+}
+
+#[test]
+fn test_board_starts_with_first_player_of_rules() {
+    let rules = CustomRules {
+        first_player: Player::Red,
+        ..custom_rules()
+    };
+
+    let board = Board::with_rules(rules);
+
+    assert_eq!(board.current_state().current_player, Player::Red);
+}
+
+#[test]
+fn test_men_capture_backward() {
+    let rules = CustomRules {
+        men_capture_backward: true,
+        ..custom_rules()
+    };
+    let board = BoardBuilder::with_rules(rules)
+        .piece(Player::Black, Square::Eighteen)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+    let state = board.current_state();
+
+    let english_moves = MoveIter::new(state, Player::Black).collect::<Vec<Move>>();
+    let moves = MoveIter::with_rules(state, Player::Black, rules).collect::<Vec<Move>>();
+
+    assert!(english_moves.iter().all(|m| m.capture().is_none()));
+    assert_eq!(
+        moves,
+        vec![Move::from_squares(Square::Eighteen, Square::Nine)]
+    );
+}
+
+#[test]
+fn test_flying_kings_move_along_entire_diagonal() {
+    let rules = CustomRules {
+        flying_kings: true,
+        ..custom_rules()
+    };
+    let board = BoardBuilder::with_rules(rules)
+        .king(Player::Black, Square::One)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+    let state = board.current_state();
+
+    let english_moves = MoveIter::new(state, Player::Black);
+    let moves = MoveIter::with_rules(state, Player::Black, rules);
+
+    assert_eq!(english_moves.count(), 2);
+    assert_eq!(moves.count(), 7);
+}
+
+#[test]
+fn test_flying_kings_capture_from_a_distance() {
+    let rules = CustomRules {
+        flying_kings: true,
+        ..custom_rules()
+    };
+    let mut board = BoardBuilder::with_rules(rules)
+        .king(Player::Black, Square::One)
+        .piece(Player::Red, Square::Fifteen)
+        .piece(Player::Red, Square::ThirtyOne)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().collect::<Vec<Turn>>();
+    assert_eq!(turns.len(), 3);
+
+    let board_state = board.push_turn("1x24").unwrap();
+    assert_eq!(
+        board_state.red_pieces,
+        BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000000_00000000_00001000)
+    );
+    assert_eq!(
+        board_state.kings,
+        BitBoard::new(0b00000000_00000000_00000000_00000000_00000000_00000010_00000000_00000000)
+    );
+}
+
+#[test]
+fn test_captures_are_optional_when_not_mandatory() {
+    let rules = CustomRules {
+        mandatory_capture: false,
+        ..custom_rules()
+    };
+    let board = BoardBuilder::with_rules(rules)
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+    let validator = MoveValidator::with_rules(board.current_state(), rules);
+
+    assert!(validator.validate("1-5").is_ok());
+    assert_eq!(board.legal_turns().count(), 4);
+}
+
+fn majority_board<R: Rules<Topology = EightByEight>>(rules: R) -> Board<R> {
+    BoardBuilder::with_rules(rules)
+        .piece(Player::Black, Square::Three)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Eight)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_any_capture_may_be_chosen_without_majority_capture() {
+    let board = majority_board(EnglishRules);
+    let validator = TurnValidator::new(board.current_state());
+
+    assert_eq!(board.legal_turns().count(), 2);
+    assert!(validator.validate("3x12").is_ok());
+}
+
+#[test]
+fn test_majority_capture_must_be_chosen() {
+    let rules = CustomRules {
+        majority_capture: true,
+        ..custom_rules()
+    };
+    let board = majority_board(rules);
+    let state = board.current_state();
+    let validator = TurnValidator::with_rules(state, rules);
+
+    let turns = LegalTurnIter::with_rules(state, rules).collect::<Vec<Turn>>();
+    assert_eq!(turns, vec![Turn::from_notation("9x18,18x27").unwrap()]);
+
+    let result = validator.validate("3x12");
+    let err = result.expect_err("Expected error when a smaller capture was chosen.");
+    assert_eq!(err, MoveError::MajorityCaptureRequired { captures: 2 });
+}
+
+#[test]
+fn test_majority_capture_keeps_quiet_turns_when_captures_are_optional() {
+    let rules = CustomRules {
+        mandatory_capture: false,
+        majority_capture: true,
+        ..custom_rules()
+    };
+    let board = majority_board(rules);
+    let state = board.current_state();
+    let validator = TurnValidator::with_rules(state, rules);
+
+    let turns = LegalTurnIter::with_rules(state, rules).collect::<Vec<Turn>>();
+    assert_eq!(turns.len(), 3);
+    assert!(turns.contains(&Turn::from_notation("9x18,18x27").unwrap()));
+    assert!(turns.contains(&Turn::from_notation("3-7").unwrap()));
+    assert!(turns.contains(&Turn::from_notation("9-13").unwrap()));
+
+    assert!(validator.validate("3-7").is_ok());
+    let result = validator.validate("3x12");
+    let err = result.expect_err("Expected error when a smaller capture was chosen.");
+    assert_eq!(err, MoveError::MajorityCaptureRequired { captures: 2 });
+}
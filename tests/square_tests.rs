@@ -1,5 +1,9 @@
 use checke_rs::bitboard::MonoBitBoard;
-use checke_rs::position::{NotationError, Square};
+use checke_rs::canadian::Square72;
+use checke_rs::international::Square50;
+use checke_rs::position::{NotationError, Orientation, Square};
+use checke_rs::turkish::Square64;
+use test_case::test_case;
 
 #[test]
 fn test_square_converts_from_valid_u8() {
@@ -57,6 +61,93 @@ fn test_square_thirty_two_produces_correct_bitboard() {
     assert_eq!(bb, expected_bb)
 }
 
+#[test_case(Square::One, Orientation::Classical, 1 << 62)]
+#[test_case(Square::One, Orientation::Mirrored, 1 << 63)]
+#[test_case(Square::One, Orientation::MirroredFromRed, 1 << 0)]
+#[test_case(Square::Five, Orientation::MirroredFromRed, 1 << 9)]
+#[test_case(Square::ThirtyTwo, Orientation::Mirrored, 1 << 0)]
+fn test_square_to_cell(square: Square, orientation: Orientation, expected: u128) {
+    assert_eq!(square.to_cell(orientation), expected);
+}
+
+#[test_case(Orientation::Classical)]
+#[test_case(Orientation::Mirrored)]
+#[test_case(Orientation::MirroredFromRed)]
+fn test_square_round_trips_through_cell(orientation: Orientation) {
+    for square in Square::iter() {
+        let cell = square.to_cell(orientation);
+        assert_eq!(Square::from_cell(cell, orientation).unwrap(), square);
+    }
+}
+
+// Cells of squares 1 to 32 as they were listed by the original conversion table of the classical
+// board, before conversions were derived from the square index.
+const BITBOARD_TABLE: [u128; 32] = [
+    0x4000000000000000,
+    0x1000000000000000,
+    0x400000000000000,
+    0x100000000000000,
+    0x80000000000000,
+    0x20000000000000,
+    0x8000000000000,
+    0x2000000000000,
+    0x400000000000,
+    0x100000000000,
+    0x40000000000,
+    0x10000000000,
+    0x8000000000,
+    0x2000000000,
+    0x800000000,
+    0x200000000,
+    0x40000000,
+    0x10000000,
+    0x4000000,
+    0x1000000,
+    0x800000,
+    0x200000,
+    0x80000,
+    0x20000,
+    0x4000,
+    0x1000,
+    0x400,
+    0x100,
+    0x80,
+    0x20,
+    0x8,
+    0x2,
+];
+
+#[test]
+fn test_square_index_matches_bitboard_table() {
+    for (square, expected) in Square::iter().zip(BITBOARD_TABLE) {
+        let index = expected.trailing_zeros() as u8;
+        let cell = MonoBitBoard::new(expected).unwrap();
+        assert_eq!(square.index(), index);
+        assert_eq!(Square::from_index(index).unwrap(), square);
+        assert_eq!(cell.square().unwrap(), square);
+        assert_eq!(MonoBitBoard::from(square), expected);
+        assert_eq!(Square::try_from(cell).unwrap(), square);
+    }
+    assert_eq!(Square::iter().count(), BITBOARD_TABLE.len());
+}
+
+#[test_case(63 ; "top left corner")]
+#[test_case(0 ; "bottom right corner")]
+#[test_case(64 ; "outside of the board")]
+fn test_square_from_unplayable_index(index: u8) {
+    assert!(Square::from_index(index).is_err());
+}
+
+#[test_case(1 << 63, Orientation::Classical)]
+#[test_case(1 << 62, Orientation::Mirrored)]
+#[test_case(1 << 1, Orientation::MirroredFromRed)]
+#[test_case(1 << 100, Orientation::Mirrored)]
+fn test_square_from_unplayable_cell(cell: u128, orientation: Orientation) {
+    let cell = MonoBitBoard::new(cell).unwrap();
+
+    assert!(Square::from_cell(cell, orientation).is_err());
+}
+
 mod move_tests {
     use test_case::test_case;
 
@@ -80,3 +171,52 @@ mod move_tests {
         assert!(is_error)
     }
 }
+
+#[test_case(Square::One, 0, 1)]
+#[test_case(Square::Four, 0, 7)]
+#[test_case(Square::Five, 1, 0)]
+#[test_case(Square::TwentyTwo, 5, 2)]
+#[test_case(Square::ThirtyTwo, 7, 6)]
+fn test_square_coordinates(square: Square, row: u8, column: u8) {
+    assert_eq!(square.row(), row);
+    assert_eq!(square.column(), column);
+    assert_eq!(Square::from_coordinates(row, column).unwrap(), square);
+}
+
+#[test_case(0, 0 ; "unplayable cell")]
+#[test_case(8, 1 ; "row outside of the board")]
+#[test_case(1, 8 ; "column outside of the board")]
+fn test_square_from_invalid_coordinates(row: u8, column: u8) {
+    assert!(Square::from_coordinates(row, column).is_err());
+}
+
+fn assert_square_type_round_trips<S>(squares: impl Iterator<Item = S>, count: u8)
+where
+    S: Copy + std::fmt::Debug + PartialEq + Into<u8> + Into<MonoBitBoard>,
+    S: TryFrom<u8, Error = NotationError> + TryFrom<MonoBitBoard>,
+    for<'a> S: TryFrom<&'a str, Error = NotationError>,
+{
+    let squares = squares.collect::<Vec<S>>();
+    assert_eq!(squares.len(), count as usize);
+    for (number, square) in (1..=count).zip(squares) {
+        let cell: MonoBitBoard = square.into();
+        assert_eq!(Into::<u8>::into(square), number);
+        assert_eq!(S::try_from(number).unwrap(), square);
+        assert_eq!(S::try_from(number.to_string().as_str()).unwrap(), square);
+        assert!(S::try_from(cell).is_ok_and(|converted| converted == square));
+    }
+    assert_eq!(S::try_from(0).unwrap_err(), NotationError::OutOfRange);
+    assert_eq!(
+        S::try_from(count + 1).unwrap_err(),
+        NotationError::OutOfRange
+    );
+    assert_eq!(S::try_from("x").unwrap_err(), NotationError::InvalidFormat);
+}
+
+#[test]
+fn test_square_types_round_trip() {
+    assert_square_type_round_trips(Square::iter(), 32);
+    assert_square_type_round_trips(Square50::iter(), 50);
+    assert_square_type_round_trips(Square64::iter(), 64);
+    assert_square_type_round_trips(Square72::iter(), 72);
+}
@@ -0,0 +1,193 @@
+use checke_rs::bitboard::{BitBoard, MonoBitBoard};
+use checke_rs::board::Player;
+use checke_rs::canadian::TwelveByTwelve;
+use checke_rs::frisian::FrisianTenByTen;
+use checke_rs::international::TenByTen;
+use checke_rs::topology::{
+    Direction, EightByEight, MirroredEightByEight, MirroredFromRedEightByEight, Topology,
+};
+use checke_rs::turkish::OrthogonalEightByEight;
+
+// Row and column offsets of the nearest playable square in the given direction on a board where
+// only the dark squares are played on.
+fn offset(direction: Direction) -> (i8, i8) {
+    match direction {
+        Direction::NorthWest => (-1, -1),
+        Direction::NorthEast => (-1, 1),
+        Direction::SouthWest => (1, -1),
+        Direction::SouthEast => (1, 1),
+        Direction::North => (-2, 0),
+        Direction::East => (0, 2),
+        Direction::South => (2, 0),
+        Direction::West => (0, -2),
+    }
+}
+
+// Calculates the expected neighbour of a square on a numbered board of the given width, where
+// square 1 sits on the second column of the top row.
+fn expected_neighbour(width: i8, number: u8, direction: Direction) -> Option<u8> {
+    let row_squares = width / 2;
+    let index = number as i8 - 1;
+    let row = index / row_squares;
+    let column = 2 * (index % row_squares) + (row + 1) % 2;
+    let (row_offset, column_offset) = offset(direction);
+    let (row, column) = (row + row_offset, column + column_offset);
+    let on_board = (0..width).contains(&row) && (0..width).contains(&column);
+    on_board.then_some((row * row_squares + column / 2 + 1) as u8)
+}
+
+fn assert_neighbours<T: Topology>(width: i8, directions: &[Direction]) {
+    let squares = (width as u8 / 2) * width as u8;
+    for number in 1..=squares {
+        let cell = T::cell(number).unwrap();
+        for direction in directions {
+            let neighbour = T::step(cell, *direction).and_then(T::number);
+            assert_eq!(
+                neighbour,
+                expected_neighbour(width, number, *direction),
+                "square {number} stepping {direction:?}"
+            );
+        }
+    }
+}
+
+fn assert_shift_matches_steps<T: Topology>(squares: u8) {
+    let cells = (1..=squares)
+        .filter_map(T::cell)
+        .fold(BitBoard::default(), |cells, cell| cells | cell);
+    for direction in Direction::ALL {
+        let stepped = cells
+            .used_cells()
+            .filter_map(|cell| T::step(cell, direction))
+            .fold(BitBoard::default(), |cells, cell| cells | cell);
+        assert_eq!(
+            T::shift(cells, direction),
+            stepped,
+            "shifting {direction:?}"
+        );
+    }
+}
+
+// Board of six rows and columns played on every cell, relying on the default coordinates of a
+// topology. Squares are numbered from the top left corner, square 1 sitting on the highest bit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct SixBySix;
+
+impl Topology for SixBySix {
+    type Square = u8;
+
+    const SIZE: u8 = 6;
+
+    fn step(_cell: MonoBitBoard, _direction: Direction) -> Option<MonoBitBoard> {
+        None
+    }
+
+    fn cell(number: u8) -> Option<MonoBitBoard> {
+        (1..=36)
+            .contains(&number)
+            .then(|| MonoBitBoard::new(1 << (36 - number)).unwrap())
+    }
+
+    fn number(cell: MonoBitBoard) -> Option<u8> {
+        let bit = cell.value().trailing_zeros() as u8;
+        (bit < 36).then_some(36 - bit)
+    }
+
+    fn initial_pieces(_player: Player) -> BitBoard {
+        BitBoard::default()
+    }
+
+    fn promotion_squares(_player: Player) -> BitBoard {
+        BitBoard::default()
+    }
+}
+
+fn assert_coordinates_round_trip<T: Topology>(squares: u8) {
+    let mut cells = vec![];
+    for row in 0..T::SIZE {
+        for column in 0..T::SIZE {
+            if let Some(cell) = T::cell_at(row, column) {
+                assert_eq!(T::coordinates(cell), Some((row, column)));
+                cells.push(cell);
+            }
+        }
+    }
+    assert_eq!(cells.len(), squares as usize);
+    assert_eq!(T::cell_at(T::SIZE, 0), None);
+    assert_eq!(T::cell_at(0, T::SIZE), None);
+}
+
+#[test]
+fn test_eight_by_eight_neighbours() {
+    assert_neighbours::<EightByEight>(8, &Direction::DIAGONAL);
+    assert_eq!(
+        EightByEight::step(EightByEight::cell(1).unwrap(), Direction::South),
+        None
+    );
+}
+
+#[test]
+fn test_ten_by_ten_neighbours() {
+    assert_neighbours::<TenByTen>(10, &Direction::ALL);
+    assert_neighbours::<FrisianTenByTen>(10, &Direction::ALL);
+}
+
+#[test]
+fn test_twelve_by_twelve_neighbours() {
+    assert_neighbours::<TwelveByTwelve>(12, &Direction::ALL);
+}
+
+#[test]
+fn test_eight_by_eight_shift_matches_steps() {
+    assert_shift_matches_steps::<EightByEight>(32);
+    assert_shift_matches_steps::<MirroredEightByEight>(32);
+    assert_shift_matches_steps::<MirroredFromRedEightByEight>(32);
+}
+
+#[test]
+fn test_packed_shift_matches_steps() {
+    assert_shift_matches_steps::<TenByTen>(50);
+    assert_shift_matches_steps::<TwelveByTwelve>(72);
+}
+
+#[test]
+fn test_orthogonal_shift_matches_steps() {
+    assert_shift_matches_steps::<OrthogonalEightByEight>(64);
+}
+
+#[test]
+fn test_coordinates_round_trip() {
+    assert_coordinates_round_trip::<EightByEight>(32);
+    assert_coordinates_round_trip::<MirroredEightByEight>(32);
+    assert_coordinates_round_trip::<MirroredFromRedEightByEight>(32);
+    assert_coordinates_round_trip::<OrthogonalEightByEight>(64);
+    assert_coordinates_round_trip::<TenByTen>(50);
+    assert_coordinates_round_trip::<FrisianTenByTen>(50);
+    assert_coordinates_round_trip::<TwelveByTwelve>(72);
+    assert_coordinates_round_trip::<SixBySix>(36);
+}
+
+#[test]
+fn test_coordinates_follow_numbering() {
+    assert_eq!(
+        EightByEight::coordinates(EightByEight::cell(1).unwrap()),
+        Some((0, 1))
+    );
+    assert_eq!(
+        MirroredEightByEight::coordinates(MirroredEightByEight::cell(1).unwrap()),
+        Some((0, 0))
+    );
+    assert_eq!(
+        TenByTen::coordinates(TenByTen::cell(46).unwrap()),
+        Some((9, 0))
+    );
+    assert_eq!(
+        TwelveByTwelve::coordinates(TwelveByTwelve::cell(72).unwrap()),
+        Some((11, 10))
+    );
+    assert_eq!(
+        SixBySix::coordinates(SixBySix::cell(8).unwrap()),
+        Some((1, 1))
+    );
+    assert_eq!(SixBySix::cell_at(5, 5), SixBySix::cell(36));
+}
@@ -0,0 +1,107 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardBuilder, Player};
+use checke_rs::position::{Move, MoveError};
+use checke_rs::turkish::{OrthogonalEightByEight, Square64, TurkishRules};
+use checke_rs::turn::Turn;
+use test_case::test_case;
+
+#[test_case(Square64::One, 1 << 63)]
+#[test_case(Square64::Nine, 1 << 55)]
+#[test_case(Square64::SixtyFour, 1 << 0)]
+fn test_square_to_bitboard(square: Square64, expected: u128) {
+    assert_eq!(MonoBitBoard::from(square), expected);
+    assert_eq!(
+        Square64::try_from(MonoBitBoard::from(square)).unwrap(),
+        square
+    );
+}
+
+#[test]
+fn test_orthogonal_notation() {
+    let m = Move::parse::<OrthogonalEightByEight>("44x42").unwrap();
+    let turn = Turn::<OrthogonalEightByEight>::try_from("41-33").unwrap();
+
+    assert_eq!(
+        m,
+        Move::try_from((Square64::FortyFour, Square64::FortyTwo)).unwrap()
+    );
+    assert_eq!(m.capture(), Some(MonoBitBoard::from(Square64::FortyThree)));
+    assert_eq!(turn.moves()[0].capture(), None);
+}
+
+#[test]
+fn test_initial_board() {
+    let mut board = Board::with_rules(TurkishRules);
+    let state = board.current_state();
+
+    assert_eq!(state.current_player, Player::Red);
+    assert_eq!(state.red_pieces().used_cells().count(), 16);
+    assert_eq!(state.black_pieces().used_cells().count(), 16);
+    assert_eq!(board.legal_turns().count(), 8);
+    assert!(board.push_turn("41-33").is_ok());
+}
+
+#[test]
+fn test_men_move_forward_and_sideways() {
+    let board = BoardBuilder::with_rules(TurkishRules)
+        .piece(Player::Red, Square64::FortyFour)
+        .piece(Player::Black, Square64::One)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 3);
+}
+
+#[test]
+fn test_men_capture_sideways_but_not_backward() {
+    let board = BoardBuilder::with_rules(TurkishRules)
+        .piece(Player::Red, Square64::FortyFour)
+        .piece(Player::Black, Square64::FortyThree)
+        .piece(Player::Black, Square64::FiftyTwo)
+        .build()
+        .unwrap();
+
+    let turns = board
+        .legal_turns()
+        .collect::<Vec<Turn<OrthogonalEightByEight>>>();
+    assert_eq!(
+        turns,
+        vec![Turn::from_moves([(Square64::FortyFour, Square64::FortyTwo)]).unwrap()]
+    );
+}
+
+#[test]
+fn test_captured_pieces_are_removed_immediately() {
+    let mut board = BoardBuilder::with_rules(TurkishRules)
+        .king(Player::Red, Square64::FortyTwo)
+        .piece(Player::Red, Square64::FortyFive)
+        .piece(Player::Black, Square64::ThirtyFour)
+        .piece(Player::Black, Square64::Twenty)
+        .piece(Player::Black, Square64::TwentyNine)
+        .piece(Player::Black, Square64::ThirtySix)
+        .build()
+        .unwrap();
+
+    // The king is able to land on and pass over the square of the first piece it captured.
+    assert_eq!(board.legal_turns().count(), 3);
+    let state = board.push_turn("42x18,18x21,21x37,37x33").unwrap();
+    assert!(state.black_pieces().empty());
+}
+
+#[test]
+fn test_captures_can_not_reverse_direction() {
+    let mut board = BoardBuilder::with_rules(TurkishRules)
+        .king(Player::Red, Square64::FortyOne)
+        .piece(Player::Red, Square64::Seventeen)
+        .piece(Player::Black, Square64::ThirtyThree)
+        .piece(Player::Black, Square64::FortyNine)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 2);
+    assert_eq!(
+        board.push_turn("41x25,25x57").unwrap_err(),
+        MoveError::CaptureReversed
+    );
+    assert!(board.push_turn("41x25").is_ok());
+}
@@ -1,5 +1,33 @@
-use checke_rs::position::{Move, Square};
-use checke_rs::turn::Turn;
+use checke_rs::board::{Board, BoardBuilder, BoardState, Player};
+use checke_rs::international::{InternationalRules, TenByTen};
+use checke_rs::position::{Move, MoveError, Notation, NotationError, Square};
+use checke_rs::rules::{CustomRules, EnglishRules, PromotionRule};
+use checke_rs::turn::{LegalTurnIter, Turn, TurnValidator};
+use test_case::test_case;
+
+fn double_jump_board() -> Board {
+    BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap()
+}
+
+fn promotion_board(promotion_rule: PromotionRule) -> Board<CustomRules> {
+    let rules = CustomRules {
+        promotion_rule,
+        ..CustomRules::default()
+    };
+    BoardBuilder::with_rules(rules)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .build()
+        .unwrap()
+}
 
 #[test]
 fn test_turn_created_correctly_from_strings() {
@@ -39,3 +67,331 @@ fn test_turn_created_correctly_from_notation() {
     ];
     assert_eq!(turn.moves(), expected_moves)
 }
+
+#[test]
+fn test_complete_capture_sequence_is_ok() {
+    let board = double_jump_board();
+    let validator = TurnValidator::new(board.current_state());
+
+    let result = validator.validate("9x18,18x27");
+
+    assert!(result.is_ok())
+}
+
+#[test]
+fn test_incomplete_capture_sequence_is_error() {
+    let board = double_jump_board();
+    let validator = TurnValidator::new(board.current_state());
+
+    let result = validator.validate("9x18");
+
+    let err = result.expect_err("Expected error when a capture sequence was not completed.");
+    assert_eq!(err, MoveError::IncompleteCapture { piece: 18 });
+}
+
+#[test]
+fn test_capture_sequence_with_mixed_pieces_is_error() {
+    let board = double_jump_board();
+    let validator = TurnValidator::new(board.current_state());
+
+    let result = validator.validate("9x18,1-5");
+
+    let err = result.expect_err("Expected error when a turn moved several pieces.");
+    assert_eq!(err, MoveError::MixedPieces);
+}
+
+#[test]
+fn test_move_after_quiet_move_is_error() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+    let validator = TurnValidator::new(board.current_state());
+
+    let result = validator.validate("1-5,5-9");
+
+    let err = result.expect_err("Expected error when moving after a non-capturing move.");
+    assert_eq!(err, MoveError::QuietMoveNotAlone);
+}
+
+#[test]
+fn test_empty_turn_is_error() {
+    let board = double_jump_board();
+    let validator = TurnValidator::new(board.current_state());
+
+    let result = validator.validate(Turn::new(Vec::<Move>::new()).unwrap());
+
+    let err = result.expect_err("Expected error when a turn contained no moves.");
+    assert_eq!(err, MoveError::EmptyTurn);
+}
+
+#[test]
+fn test_legal_turns_from_initial_state() {
+    let board_state = BoardState::default();
+
+    let turns = board_state.legal_turns().collect::<Vec<Turn>>();
+
+    assert_eq!(turns.len(), 7);
+    assert!(turns.iter().all(|turn| turn.moves().len() == 1));
+}
+
+#[test]
+fn test_legal_turns_follow_every_capture_path() {
+    let board = BoardBuilder::default()
+        .piece(Player::Black, Square::One)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    let turns = LegalTurnIter::new(board.current_state()).collect::<Vec<Turn>>();
+
+    assert_eq!(turns.len(), 2);
+    assert!(turns.contains(&Turn::from_notation("9x18,18x25").unwrap()));
+    assert!(turns.contains(&Turn::from_notation("9x18,18x27").unwrap()));
+}
+
+#[test]
+fn test_legal_turns_are_valid_turns() {
+    let board = double_jump_board();
+    let validator = TurnValidator::new(board.current_state());
+
+    let turns = board.current_state().legal_turns().collect::<Vec<Turn>>();
+
+    assert_eq!(turns, vec![Turn::from_notation("9x18,18x27").unwrap()]);
+    assert!(turns
+        .into_iter()
+        .all(|turn| validator.validate(turn).is_ok()));
+}
+
+#[test]
+fn test_crowning_ends_turn() {
+    let board = promotion_board(PromotionRule::EndsTurn);
+    let validator = TurnValidator::new(board.current_state());
+
+    assert!(validator.validate("22x31").is_ok());
+
+    let result = validator.validate("22x31,31x24");
+
+    let err = result.expect_err("Expected error when moving after being crowned.");
+    assert_eq!(err, MoveError::TurnEndedByPromotion);
+}
+
+#[test]
+fn test_crowning_continues_capture_as_king() {
+    let board = promotion_board(PromotionRule::ContinuesAsKing);
+    let state = board.current_state();
+    let validator = TurnValidator::with_rules(state, *board.rules());
+
+    assert!(validator.validate("22x31,31x24").is_ok());
+
+    let result = validator.validate("22x31");
+
+    let err = result.expect_err("Expected error when the new king stopped capturing.");
+    assert_eq!(err, MoveError::IncompleteCapture { piece: 31 });
+}
+
+#[test]
+fn test_legal_turns_stop_at_promotion() {
+    let board = promotion_board(PromotionRule::EndsTurn);
+
+    let turns = board.legal_turns().collect::<Vec<Turn>>();
+
+    assert_eq!(turns, vec![Turn::from_notation("22x31").unwrap()]);
+}
+
+#[test]
+fn test_legal_turns_continue_after_promotion() {
+    let board = promotion_board(PromotionRule::ContinuesAsKing);
+
+    let turns = board.legal_turns().collect::<Vec<Turn>>();
+
+    assert_eq!(turns, vec![Turn::from_notation("22x31,31x24").unwrap()]);
+}
+
+fn branching_capture_board() -> Board {
+    BoardBuilder::default()
+        .piece(Player::Black, Square::Ten)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::Fifteen)
+        .piece(Player::Red, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap()
+}
+
+#[test_case("9x18x27" ; "chain")]
+#[test_case("9X18x27" ; "upper case chain")]
+#[test_case("9x18,18x27" ; "separate captures")]
+fn test_multi_jump_notation_is_parsed(notation: &str) {
+    let turn = Turn::from_notation(notation).unwrap();
+
+    assert_eq!(turn, Turn::new(["9x18", "18x27"]).unwrap());
+    assert_eq!(turn.moves()[1].capture(), Some(Square::TwentyThree.into()));
+}
+
+#[test_case("9-18x27")]
+#[test_case("9x18-27x36")]
+#[test_case("9xx18")]
+fn test_invalid_multi_jump_notation(notation: &str) {
+    assert_eq!(
+        Turn::from_notation(notation).unwrap_err(),
+        NotationError::InvalidFormat
+    );
+}
+
+#[test_case("9x27" ; "shortened")]
+#[test_case("9x18x27" ; "chain")]
+#[test_case("9x18,18x27" ; "separate captures")]
+fn test_shortened_capture_is_resolved(notation: &str) {
+    let board = double_jump_board();
+
+    assert_eq!(
+        Turn::resolve_notation(notation, board.current_state()).unwrap(),
+        Turn::from_notation("9x18,18x27").unwrap()
+    );
+}
+
+#[test_case("10x17x26", "10x17,17x26")]
+#[test_case("10x19x26", "10x19,19x26")]
+fn test_capture_path_is_narrowed_down(notation: &str, expected: &str) {
+    let board = branching_capture_board();
+
+    assert_eq!(
+        Turn::resolve_notation(notation, board.current_state()).unwrap(),
+        Turn::from_notation(expected).unwrap()
+    );
+}
+
+#[test_case("10x26", NotationError::AmbiguousTurn ; "ambiguous")]
+#[test_case("10x28", NotationError::NoLegalTurn ; "unreachable square")]
+#[test_case("10-14", NotationError::NoLegalTurn ; "quiet move while capture is required")]
+#[test_case("10x17", NotationError::NoLegalTurn ; "incomplete capture")]
+#[test_case("10x33", NotationError::OutOfRange ; "out of range")]
+fn test_unresolvable_notation(notation: &str, expected: NotationError) {
+    let board = branching_capture_board();
+
+    assert_eq!(
+        Turn::resolve_notation(notation, board.current_state()).unwrap_err(),
+        expected
+    );
+}
+
+#[test]
+fn test_quiet_move_is_resolved() {
+    let board = Board::default();
+
+    assert_eq!(
+        Turn::resolve_notation("11-15", board.current_state()).unwrap(),
+        Turn::from_notation("11-15").unwrap()
+    );
+    assert_eq!(
+        Turn::resolve_notation("11x15", board.current_state()).unwrap_err(),
+        NotationError::NoLegalTurn
+    );
+}
+
+#[test_case("11-15", "11-15")]
+#[test_case("9x18,18x27", "9x18x27")]
+#[test_case("22x31", "22x31")]
+fn test_turn_is_written_in_standard_notation(notation: &str, expected: &str) {
+    let turn = Turn::from_notation(notation).unwrap();
+
+    assert_eq!(turn.notation().as_deref(), Some(expected));
+    assert_eq!(turn.to_string(), expected);
+}
+
+#[test]
+fn test_legal_turns_are_written_compactly() {
+    let board = branching_capture_board();
+
+    let notations = board
+        .legal_turns()
+        .map(|turn| turn.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(notations, ["10x17x26", "10x19x26"]);
+}
+
+#[test_case("c3-d4", "22-18")]
+#[test_case("b6xd4xf2", "9x18x27")]
+#[test_case("b6xd4,d4xf2", "9x18x27")]
+fn test_algebraic_turn_is_parsed(algebraic: &str, numeric: &str) {
+    let turn = Turn::parse_with(algebraic, Notation::Algebraic).unwrap();
+
+    assert_eq!(turn, Turn::from_notation(numeric).unwrap());
+    assert_eq!(
+        turn.format_with(Notation::Numeric).as_deref(),
+        Some(numeric)
+    );
+}
+
+#[test_case("22-18", "c3-d4")]
+#[test_case("9x18,18x27", "b6xd4xf2")]
+fn test_turn_is_written_in_algebraic_notation(numeric: &str, expected: &str) {
+    let turn = Turn::from_notation(numeric).unwrap();
+
+    assert_eq!(
+        turn.format_with(Notation::Algebraic).as_deref(),
+        Some(expected)
+    );
+}
+
+#[test]
+fn test_turn_off_the_board_is_not_written() {
+    let turn = Turn::<TenByTen>::from_moves([(Square::One, Square::Five)]).unwrap();
+
+    assert_eq!(turn.format_with(Notation::Numeric), None);
+    assert_eq!(turn.format_with(Notation::Algebraic), None);
+    assert_eq!(turn.notation(), None);
+    assert_eq!(turn.to_string(), "[62]-[55]");
+}
+
+#[test]
+fn test_legal_turns_are_written_algebraically() {
+    let board = branching_capture_board();
+
+    let notations = board
+        .legal_turns()
+        .map(|turn| turn.format_with(Notation::Algebraic).unwrap())
+        .collect::<Vec<String>>();
+    assert_eq!(notations, ["d6xb4xd2", "d6xf4xd2"]);
+}
+
+#[test_case("d6xf4xd2", Ok("10x19x26") ; "full path")]
+#[test_case("d6xd2", Err(NotationError::AmbiguousTurn) ; "ambiguous shortened path")]
+#[test_case("d6-d2", Err(NotationError::NoLegalTurn) ; "quiet move")]
+fn test_algebraic_notation_is_resolved(notation: &str, expected: Result<&str, NotationError>) {
+    let board = branching_capture_board();
+
+    let turn = Turn::resolve_with(
+        notation,
+        board.current_state(),
+        EnglishRules,
+        Notation::Algebraic,
+    );
+    assert_eq!(
+        turn,
+        expected.map(|numeric| Turn::from_notation(numeric).unwrap())
+    );
+}
+
+#[test]
+fn test_algebraic_turn_on_ten_by_ten_board() {
+    let board = Board::with_rules(InternationalRules);
+
+    let turn = Turn::<TenByTen>::resolve_with(
+        "d4-e5",
+        board.current_state(),
+        InternationalRules,
+        Notation::Algebraic,
+    )
+    .unwrap();
+    assert_eq!(turn, Turn::<TenByTen>::parse("32-28").unwrap());
+    assert_eq!(
+        turn.format_with(Notation::Algebraic).as_deref(),
+        Some("d4-e5")
+    );
+}
@@ -0,0 +1,472 @@
+use checke_rs::bitboard::MonoBitBoard;
+use checke_rs::board::{Board, BoardBuilder, BoardStatus, Player};
+use checke_rs::position::{Move, MoveError, MoveIter, Orientation, Square};
+use checke_rs::rules::{
+    BrazilianRules, GiveawayRules, ItalianRules, PoolRules, PortugueseRules, RussianRules,
+    SpanishRules,
+};
+use checke_rs::topology::{MirroredEightByEight, Topology};
+
+#[test]
+fn test_russian_board_starts_with_red() {
+    let board = Board::with_rules(RussianRules);
+
+    assert_eq!(board.current_state().current_player, Player::Red);
+    assert_eq!(board.legal_turns().count(), 7);
+}
+
+#[test]
+fn test_russian_man_crowned_mid_capture_continues_as_king() {
+    let mut board = BoardBuilder::with_rules(RussianRules)
+        .piece(Player::Red, Square::Eleven)
+        .piece(Player::Black, Square::Seven)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Black, Square::TwentyEight)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("11x2").unwrap_err(),
+        MoveError::IncompleteCapture { piece: 2 }
+    );
+    let state = board.push_turn("11x2,2x13").unwrap();
+    assert!(state.is_king(MonoBitBoard::from(Square::Thirteen)));
+    assert!(!state.is_piece(MonoBitBoard::from(Square::Nine)));
+}
+
+#[test]
+fn test_russian_men_capture_backward_and_kings_fly() {
+    let mut board = BoardBuilder::with_rules(RussianRules)
+        .piece(Player::Red, Square::Eighteen)
+        .king(Player::Red, Square::ThirtyTwo)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Black, Square::Nineteen)
+        .build()
+        .unwrap();
+
+    assert!(board.push_turn("18x25").is_ok());
+    assert!(board.push_turn("19-23").is_ok());
+    assert!(board.push_turn("32x9").is_ok());
+}
+
+#[test]
+fn test_russian_king_must_land_where_capture_continues() {
+    let mut board = BoardBuilder::with_rules(RussianRules)
+        .current_player(Player::Red)
+        .king(Player::Red, Square::TwentyNine)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Black, Square::Ten)
+        .piece(Player::Black, Square::One)
+        .build()
+        .unwrap();
+
+    let turns = board
+        .legal_turns()
+        .map(|turn| turn.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(turns, ["29x15x6"]);
+    assert_eq!(
+        board.push_turn("29x18").unwrap_err(),
+        MoveError::IncompleteCapture { piece: 29 }
+    );
+    assert!(board.push_turn("29x15x6").is_ok());
+}
+
+#[test]
+fn test_italian_board_is_mirrored() {
+    let board = Board::with_rules(ItalianRules);
+    let top_left_corner = MonoBitBoard::new(1 << 63).unwrap();
+
+    assert_eq!(MirroredEightByEight::cell(1), Some(top_left_corner));
+    assert!(board.current_state().is_black_piece(top_left_corner));
+    assert_eq!(board.current_state().current_player, Player::Red);
+    assert_eq!(board.legal_turns().count(), 7);
+}
+
+#[test]
+fn test_italian_men_can_not_capture_kings() {
+    let mut board = BoardBuilder::with_rules(ItalianRules)
+        .piece(Player::Red, Square::TwentyTwo)
+        .king(Player::Black, Square::Eighteen)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("22x13").unwrap_err(),
+        MoveError::InvalidCapture
+    );
+    assert!(board.push_turn("22-19").is_ok());
+}
+
+#[test]
+fn test_italian_capture_must_be_made_with_king() {
+    let mut board = BoardBuilder::with_rules(ItalianRules)
+        .piece(Player::Red, Square::TwentyTwo)
+        .king(Player::Red, Square::TwentyEight)
+        .piece(Player::Black, Square::Eighteen)
+        .piece(Player::Black, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("22x13").unwrap_err(),
+        MoveError::KingCaptureRequired
+    );
+    assert!(board.push_turn("28x19").is_ok());
+}
+
+#[test]
+fn test_italian_capture_must_take_most_kings() {
+    let mut board = BoardBuilder::with_rules(ItalianRules)
+        .king(Player::Red, Square::TwentySeven)
+        .king(Player::Black, Square::TwentyTwo)
+        .piece(Player::Black, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("27x20").unwrap_err(),
+        MoveError::MostKingsCaptureRequired { kings: 1 }
+    );
+    assert!(board.push_turn("27x18").is_ok());
+}
+
+#[test]
+fn test_italian_capture_must_take_king_earliest() {
+    let mut board = BoardBuilder::with_rules(ItalianRules)
+        .king(Player::Red, Square::TwentySeven)
+        .king(Player::Black, Square::TwentyTwo)
+        .piece(Player::Black, Square::Thirteen)
+        .piece(Player::Black, Square::TwentyThree)
+        .king(Player::Black, Square::Fifteen)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 1);
+    assert_eq!(
+        board.push_turn("27x20,20x11").unwrap_err(),
+        MoveError::EarliestKingCaptureRequired
+    );
+    assert!(board.push_turn("27x18,18x9").is_ok());
+}
+
+#[test]
+fn test_english_men_capture_forward_only() {
+    let board = BoardBuilder::default()
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::Eighteen)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+
+    let moves = MoveIter::new(board.current_state(), Player::Black).collect::<Vec<Move>>();
+    assert_eq!(
+        moves,
+        vec![
+            Move::from_squares(Square::Eighteen, Square::TwentyTwo),
+            Move::from_squares(Square::Eighteen, Square::TwentyThree),
+        ]
+    );
+}
+
+#[test]
+fn test_brazilian_men_capture_backward() {
+    let board = BoardBuilder::with_rules(BrazilianRules)
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::Eighteen)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+
+    let moves = MoveIter::with_rules(board.current_state(), Player::Black, BrazilianRules)
+        .collect::<Vec<Move>>();
+    assert_eq!(
+        moves,
+        vec![Move::from_squares(Square::Eighteen, Square::Nine)]
+    );
+}
+
+#[test]
+fn test_pool_men_capture_backward() {
+    let board = BoardBuilder::with_rules(PoolRules)
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::Eighteen)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+
+    let moves = MoveIter::with_rules(board.current_state(), Player::Black, PoolRules)
+        .collect::<Vec<Move>>();
+    assert_eq!(
+        moves,
+        vec![Move::from_squares(Square::Eighteen, Square::Nine)]
+    );
+}
+
+#[test]
+fn test_english_kings_move_a_single_square() {
+    let board = BoardBuilder::default()
+        .current_player(Player::Black)
+        .king(Player::Black, Square::TwentyNine)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().map(|turn| turn.to_string());
+    assert_eq!(turns.collect::<Vec<String>>(), ["29-25"]);
+}
+
+#[test]
+fn test_brazilian_kings_fly() {
+    let mut board = BoardBuilder::with_rules(BrazilianRules)
+        .current_player(Player::Black)
+        .king(Player::Black, Square::TwentyNine)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 7);
+    assert!(board.push_turn("29-4").is_ok());
+}
+
+#[test]
+fn test_pool_kings_fly() {
+    let mut board = BoardBuilder::with_rules(PoolRules)
+        .current_player(Player::Black)
+        .king(Player::Black, Square::TwentyNine)
+        .piece(Player::Red, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 7);
+    assert!(board.push_turn("29-22").is_ok());
+}
+
+#[test]
+fn test_pool_king_must_land_where_capture_continues() {
+    let mut board = BoardBuilder::with_rules(PoolRules)
+        .current_player(Player::Red)
+        .king(Player::Red, Square::TwentyNine)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Black, Square::Ten)
+        .piece(Player::Black, Square::One)
+        .build()
+        .unwrap();
+
+    let turns = board
+        .legal_turns()
+        .map(|turn| turn.to_string())
+        .collect::<Vec<String>>();
+    assert_eq!(turns, ["29x15x6"]);
+    assert_eq!(
+        board.push_turn("29x11").unwrap_err(),
+        MoveError::IncompleteCapture { piece: 29 }
+    );
+    assert!(board.push_turn("29x15x6").is_ok());
+}
+
+#[test]
+fn test_english_man_is_crowned_ending_capture() {
+    let mut board = BoardBuilder::default()
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .build()
+        .unwrap();
+
+    let state = board.push_turn("22x31").unwrap();
+    assert!(state.is_king(MonoBitBoard::from(Square::ThirtyOne)));
+    assert!(state.is_red_piece(MonoBitBoard::from(Square::TwentySeven)));
+}
+
+#[test]
+fn test_brazilian_man_continues_capturing_past_king_row() {
+    let mut board = BoardBuilder::with_rules(BrazilianRules)
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("22x31").unwrap_err(),
+        MoveError::IncompleteCapture { piece: 31 }
+    );
+    let state = board.push_turn("22x31,31x24").unwrap();
+    assert!(state.black_kings().empty());
+}
+
+#[test]
+fn test_pool_man_continues_capturing_past_king_row() {
+    let mut board = BoardBuilder::with_rules(PoolRules)
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::TwentyTwo)
+        .piece(Player::Red, Square::TwentySix)
+        .piece(Player::Red, Square::TwentySeven)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("22x31").unwrap_err(),
+        MoveError::IncompleteCapture { piece: 31 }
+    );
+    let state = board.push_turn("22x31,31x24").unwrap();
+    assert!(state.black_kings().empty());
+}
+
+#[test]
+fn test_english_capture_may_take_fewer_pieces() {
+    let mut board = BoardBuilder::default()
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::Three)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Eight)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().map(|turn| turn.to_string());
+    assert_eq!(turns.collect::<Vec<String>>(), ["9x18x27", "3x12"]);
+    assert!(board.push_turn("3x12").is_ok());
+}
+
+#[test]
+fn test_brazilian_capture_must_take_most_pieces() {
+    let mut board = BoardBuilder::with_rules(BrazilianRules)
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::Three)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Eight)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().map(|turn| turn.to_string());
+    assert_eq!(turns.collect::<Vec<String>>(), ["9x18x27"]);
+    assert_eq!(
+        board.push_turn("3x12").unwrap_err(),
+        MoveError::MajorityCaptureRequired { captures: 2 }
+    );
+}
+
+#[test]
+fn test_pool_capture_may_take_fewer_pieces() {
+    let mut board = BoardBuilder::with_rules(PoolRules)
+        .current_player(Player::Black)
+        .piece(Player::Black, Square::Three)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Eight)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Red, Square::TwentyThree)
+        .build()
+        .unwrap();
+
+    let turns = board.legal_turns().map(|turn| turn.to_string());
+    assert_eq!(turns.collect::<Vec<String>>(), ["9x18x27", "3x12"]);
+    assert!(board.push_turn("3x12").is_ok());
+}
+
+#[test]
+fn test_brazilian_board_starts_with_red() {
+    let board = Board::with_rules(BrazilianRules);
+
+    assert_eq!(
+        Board::default().current_state().current_player,
+        Player::Black
+    );
+    assert_eq!(board.current_state().current_player, Player::Red);
+    assert_eq!(board.legal_turns().count(), 7);
+}
+
+#[test]
+fn test_spanish_board_is_numbered_from_red() {
+    let board = Board::with_rules(SpanishRules);
+    let state = board.current_state();
+
+    assert_eq!(state.current_player, Player::Red);
+    assert!(state.is_red_piece(MonoBitBoard::new(1).unwrap()));
+    assert!(state.is_red_piece(Square::Twelve.to_cell(Orientation::MirroredFromRed)));
+    assert!(state.is_black_piece(Square::TwentyOne.to_cell(Orientation::MirroredFromRed)));
+    assert_eq!(board.legal_turns().count(), 7);
+}
+
+#[test]
+fn test_spanish_men_capture_forward_only() {
+    let board = BoardBuilder::with_rules(SpanishRules)
+        .piece(Player::Red, Square::Fourteen)
+        .piece(Player::Black, Square::Ten)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 2);
+}
+
+#[test]
+fn test_portuguese_capture_must_take_most_kings() {
+    let mut board = BoardBuilder::with_rules(PortugueseRules::default())
+        .king(Player::Red, Square::Fourteen)
+        .king(Player::Black, Square::Nineteen)
+        .piece(Player::Black, Square::Eighteen)
+        .build()
+        .unwrap();
+
+    assert_eq!(board.legal_turns().count(), 3);
+    assert_eq!(
+        board.push_turn("14x21").unwrap_err(),
+        MoveError::MostKingsCaptureRequired { kings: 1 }
+    );
+    assert!(board.push_turn("14x32").is_ok());
+}
+
+#[test]
+fn test_giveaway_player_losing_every_piece_wins() {
+    let mut board = BoardBuilder::with_rules(GiveawayRules)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+
+    assert!(board.push_turn("9x18").is_ok());
+    assert_eq!(
+        board.status(),
+        BoardStatus::Complete {
+            winner: Player::Red
+        }
+    );
+}
+
+#[test]
+fn test_giveaway_player_without_moves_wins() {
+    let board = BoardBuilder::with_rules(GiveawayRules)
+        .current_player(Player::Red)
+        .piece(Player::Red, Square::One)
+        .piece(Player::Black, Square::ThirtyTwo)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.status(),
+        BoardStatus::Complete {
+            winner: Player::Red
+        }
+    );
+}
+
+#[test]
+fn test_giveaway_captures_are_mandatory() {
+    let mut board = BoardBuilder::with_rules(GiveawayRules)
+        .piece(Player::Black, Square::Nine)
+        .piece(Player::Black, Square::One)
+        .piece(Player::Red, Square::Fourteen)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        board.push_turn("1-6").unwrap_err(),
+        MoveError::CaptureRequired { pieces: vec![9] }
+    );
+}